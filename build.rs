@@ -0,0 +1,49 @@
+//! Embeds the version and a hash of the bundled `@tauri-apps/api` JS into the crate,
+//! see `app::bundled_api_version` and `app::bundled_api_hash`.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let manifest_dir = Path::new(&manifest_dir);
+
+    let package_json = manifest_dir.join("tauri/tooling/api/package.json");
+    println!("cargo:rerun-if-changed={}", package_json.display());
+
+    // the submodule is not checked out when building from a published crate
+    if let Some(version) = fs::read_to_string(&package_json)
+        .ok()
+        .and_then(|contents| package_version(&contents))
+    {
+        println!("cargo:rustc-env=TAURI_SYS_BUNDLED_API_VERSION={}", version);
+    }
+
+    let mut bundles: Vec<_> = fs::read_dir(manifest_dir.join("src"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "js"))
+        .collect();
+    bundles.sort();
+
+    let mut hash = FNV_OFFSET_BASIS;
+    for path in bundles {
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        for byte in fs::read(&path).unwrap() {
+            hash = (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME);
+        }
+    }
+    println!("cargo:rustc-env=TAURI_SYS_BUNDLED_API_HASH={:016x}", hash);
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Extracts the top-level `"version"` field without pulling in a JSON parser.
+fn package_version(contents: &str) -> Option<String> {
+    let (_, rest) = contents.split_once("\"version\"")?;
+    let (_, rest) = rest.split_once('"')?;
+    let (version, _) = rest.split_once('"')?;
+
+    Some(version.to_string())
+}
@@ -6,7 +6,7 @@ pub async fn test() -> anyhow::Result<()> {
 
     let text = clipboard::read_text().await?;
 
-    ensure!(text == "foobar".to_string());
+    ensure!(text.as_deref() == Some("foobar"));
 
     Ok(())
 }
@@ -1,20 +1,15 @@
 use std::time::Duration;
 
 use futures::StreamExt;
-use tauri_sys::global_shortcut;
+use tauri_sys::global_shortcut::ShortcutSet;
 
 pub async fn register_all() -> anyhow::Result<()> {
     let task = async {
-        let shortcuts = ["CommandOrControl+Shift+C", "Ctrl+Alt+F12"];
-
-        let streams = futures::future::try_join_all(shortcuts.map(|s| async move {
-            let stream = global_shortcut::register(s).await?;
-
-            anyhow::Ok(stream.map(move |_| s))
-        }))
-        .await?;
-
-        let mut events = futures::stream::select_all(streams);
+        let mut events = ShortcutSet::builder()
+            .add("CommandOrControl+Shift+C")
+            .add("Ctrl+Alt+F12")
+            .register()
+            .await?;
 
         while let Some(shortcut) = events.next().await {
             log::debug!("Shortcut {} triggered", shortcut)
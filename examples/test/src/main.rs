@@ -180,6 +180,7 @@ fn main() {
                         Test(name="os::tempdir",test=os::tempdir())
                         Test(name="os::kind",test=os::kind())
                         Test(name="os::version",test=os::version())
+                        Test(name="os::info",test=os::info())
                         Test(name="notification::is_permission_granted",test=notification::is_permission_granted())
                         Test(name="notification::request_permission",test=notification::request_permission())
                         InteractiveTest(name="notification::show_notification",test=notification::show_notification())
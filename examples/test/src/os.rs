@@ -39,3 +39,11 @@ pub async fn version() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+pub async fn info() -> anyhow::Result<()> {
+    let info = os::info().await?;
+
+    log::debug!("{:?}", info);
+
+    Ok(())
+}
@@ -16,7 +16,8 @@
 //! ```
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
-use semver::Version;
+use semver::{Version, VersionReq};
+use serde::Serialize;
 
 /// Gets the application name.
 ///
@@ -30,7 +31,7 @@ use semver::Version;
 pub async fn get_name() -> crate::Result<String> {
     let js_val = inner::getName().await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "app::get_name")
 }
 
 /// Gets the application version.
@@ -46,7 +47,7 @@ pub async fn get_name() -> crate::Result<String> {
 pub async fn get_version() -> crate::Result<Version> {
     let js_val = inner::getVersion().await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "app::get_version")
 }
 
 /// Gets the Tauri version.
@@ -62,7 +63,123 @@ pub async fn get_version() -> crate::Result<Version> {
 pub async fn get_tauri_version() -> crate::Result<Version> {
     let js_val = inner::getTauriVersion().await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "app::get_tauri_version")
+}
+
+/// The versions of the running application and the Tauri runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuntimeVersions {
+    /// The version of the application.
+    pub app_version: Version,
+    /// The version of the Tauri runtime.
+    pub tauri_version: Version,
+    /// The major version of the IPC API spoken by the runtime, the bundled JS API of this crate speaks `1`.
+    ///
+    /// The webview version is not exposed by the v1 backend.
+    pub api_compat_level: u64,
+}
+
+/// Gets the versions of the running application and the Tauri runtime.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::app::runtime_versions;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let versions = runtime_versions().await?;
+/// log::info!("Running on Tauri {}", versions.tauri_version);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn runtime_versions() -> crate::Result<RuntimeVersions> {
+    let (app_version, tauri_version) = futures::try_join!(get_version(), get_tauri_version())?;
+
+    Ok(RuntimeVersions {
+        api_compat_level: tauri_version.major,
+        app_version,
+        tauri_version,
+    })
+}
+
+/// Checks that the Tauri runtime satisfies the given semver requirement, e.g. `>=1.5`.
+///
+/// Returns the runtime version if it does.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::app::require_tauri_version;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// if require_tauri_version(">=1.5").await.is_ok() {
+///     // use APIs introduced in 1.5
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn require_tauri_version(requirement: &str) -> crate::Result<Version> {
+    let req = VersionReq::parse(requirement)
+        .map_err(|err| crate::Error::InvalidVersionRequirement(err.to_string()))?;
+    let version = get_tauri_version().await?;
+
+    if req.matches(&version) {
+        Ok(version)
+    } else {
+        Err(crate::Error::UnsupportedTauriVersion {
+            required: requirement.to_string(),
+            found: version.to_string(),
+        })
+    }
+}
+
+/// The version of the `@tauri-apps/api` package the bundled JS was built from.
+///
+/// Returns `None` if the crate was built without the `tauri` submodule checked out.
+pub const fn bundled_api_version() -> Option<&'static str> {
+    option_env!("TAURI_SYS_BUNDLED_API_VERSION")
+}
+
+/// A hash of the bundled JS, useful to tell builds apart in bug reports.
+pub const fn bundled_api_hash() -> &'static str {
+    env!("TAURI_SYS_BUNDLED_API_HASH")
+}
+
+/// Checks that the Tauri runtime is compatible with the bundled JS API, i.e. has the same major and at least the same minor version.
+///
+/// Logs a warning and returns [`Error::UnsupportedTauriVersion`](crate::Error::UnsupportedTauriVersion) otherwise,
+/// calls into an older runtime fail with opaque errors. Returns the runtime version if the check passed or the bundled version is unknown.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::app::check_bundled_api_version;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// check_bundled_api_version().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn check_bundled_api_version() -> crate::Result<Version> {
+    let Some(bundled) = bundled_api_version().and_then(|v| Version::parse(v).ok()) else {
+        log::debug!("Bundled API version is unknown, skipping the runtime version check");
+        return get_tauri_version().await;
+    };
+
+    let requirement = format!("^{}.{}", bundled.major, bundled.minor);
+    let result = require_tauri_version(&requirement).await;
+
+    if let Err(crate::Error::UnsupportedTauriVersion { found, .. }) = &result {
+        log::warn!(
+            "The bundled JS API {} (hash {}) requires Tauri {}, but the runtime is {}",
+            bundled,
+            bundled_api_hash(),
+            requirement,
+            found
+        );
+    }
+
+    result
 }
 
 /// Shows the application on macOS. This function does not automatically focus the apps windows.
@@ -97,6 +214,48 @@ pub async fn hide() -> crate::Result<()> {
     Ok(inner::hide().await?)
 }
 
+/// Runs the given cleanup tasks concurrently, then exits the app with `exit_code`.
+///
+/// Frontends get no notification when the process exits, so this is the place to flush
+/// unsaved state, e.g. persist settings or window geometry, before calling [`process::exit`](crate::process::exit).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::{app::exit_with_cleanup, appdirs};
+///
+/// # async fn main() {
+/// exit_with_cleanup(0, [Box::pin(async {
+///     let _ = appdirs::write_config("settings.json", &"dark").await;
+/// })])
+/// .await;
+/// # }
+/// ```
+#[cfg(feature = "process")]
+pub async fn exit_with_cleanup<I>(exit_code: i32, cleanup: I) -> !
+where
+    I: IntoIterator,
+    I::Item: std::future::Future,
+{
+    futures::future::join_all(cleanup).await;
+
+    crate::process::exit(exit_code).await
+}
+
+/// Runs the given cleanup tasks concurrently, then restarts the app.
+///
+/// See [`exit_with_cleanup`] for details.
+#[cfg(feature = "process")]
+pub async fn relaunch_with_cleanup<I>(cleanup: I)
+where
+    I: IntoIterator,
+    I::Item: std::future::Future,
+{
+    futures::future::join_all(cleanup).await;
+
+    crate::process::relaunch();
+}
+
 mod inner {
     use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -0,0 +1,124 @@
+//! Convenience helpers for the files an app keeps in its config and data directories.
+//!
+//! Combines the [`path`](crate::path) and [`fs`](crate::fs) modules: directories are created on first use and
+//! settings files are read and written as JSON.
+//!
+//! The `fs` scope must allow the app directories in `tauri.conf.json`:
+//! ```json
+//! {
+//!   "tauri": {
+//!     "allowlist": {
+//!       "fs": {
+//!         "readFile": true,
+//!         "writeFile": true,
+//!         "createDir": true,
+//!         "renameFile": true,
+//!         "removeFile": true,
+//!         "exists": true,
+//!         "scope": ["$APPCONFIG/**", "$APPDATA/**"]
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+
+use crate::fs::{self, BaseDirectory};
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Returns the app's config directory, creating it if it doesn't exist yet.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::appdirs;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dir = appdirs::config_dir().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn config_dir() -> crate::Result<PathBuf> {
+    let dir = crate::path::app_config_dir().await?;
+    fs::create_dir_all(&dir, BaseDirectory::AppConfig).await?;
+
+    Ok(dir)
+}
+
+/// Returns the app's data directory, creating it if it doesn't exist yet.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::appdirs;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let dir = appdirs::data_dir().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn data_dir() -> crate::Result<PathBuf> {
+    let dir = crate::path::app_data_dir().await?;
+    fs::create_dir_all(&dir, BaseDirectory::AppData).await?;
+
+    Ok(dir)
+}
+
+/// Reads the JSON file `name` from the app's config directory.
+///
+/// Returns `None` if the file doesn't exist yet.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::appdirs;
+///
+/// #[derive(Default, serde::Serialize, serde::Deserialize)]
+/// struct Settings {
+///     theme: String,
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let settings: Settings = appdirs::read_config("settings.json").await?.unwrap_or_default();
+/// # Ok(())
+/// # }
+/// ```
+pub async fn read_config<T: DeserializeOwned>(name: impl AsRef<Path>) -> crate::Result<Option<T>> {
+    read_json(name.as_ref(), BaseDirectory::AppConfig).await
+}
+
+/// Writes `value` as JSON to the file `name` in the app's config directory, creating the directory if needed.
+///
+/// The file is replaced atomically, see [`fs::write_text_file_atomic`].
+pub async fn write_config<T: Serialize>(name: impl AsRef<Path>, value: &T) -> crate::Result<()> {
+    let path = config_dir().await?.join(name);
+
+    fs::write_json(&path, value, BaseDirectory::AppConfig).await
+}
+
+/// Reads the JSON file `name` from the app's data directory.
+///
+/// Returns `None` if the file doesn't exist yet.
+pub async fn read_data<T: DeserializeOwned>(name: impl AsRef<Path>) -> crate::Result<Option<T>> {
+    read_json(name.as_ref(), BaseDirectory::AppData).await
+}
+
+/// Writes `value` as JSON to the file `name` in the app's data directory, creating the directory if needed.
+///
+/// The file is replaced atomically, see [`fs::write_text_file_atomic`].
+pub async fn write_data<T: Serialize>(name: impl AsRef<Path>, value: &T) -> crate::Result<()> {
+    let path = data_dir().await?.join(name);
+
+    fs::write_json(&path, value, BaseDirectory::AppData).await
+}
+
+async fn read_json<T: DeserializeOwned>(
+    name: &Path,
+    dir: BaseDirectory,
+) -> crate::Result<Option<T>> {
+    if !fs::exists(name, dir.clone()).await? {
+        return Ok(None);
+    }
+
+    Ok(Some(fs::read_json(name, dir).await?))
+}
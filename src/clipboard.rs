@@ -16,22 +16,45 @@
 //! ```
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
+use futures::Stream;
+use std::time::Duration;
+
 /// Gets the clipboard content as plain text.
 ///
+/// Returns `None` if the clipboard is empty or doesn't contain text.
+///
 /// # Example
 ///
 /// ```rust,no_run
 /// use tauri_api::clipboard::read_text;
 ///
-/// let clipboard_text = read_text().await;
+/// let clipboard_text = read_text().await?;
 /// ```
 /// 
 /// Requires [`allowlist > clipboard > readText`](https://tauri.app/v1/api/config#clipboardallowlistconfig.readtext) to be enabled.
 #[inline(always)]
-pub async fn read_text() -> crate::Result<String> {
+pub async fn read_text() -> crate::Result<Option<String>> {
     let js_val = inner::readText().await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "clipboard::read_text")
+}
+
+/// Checks whether the clipboard currently contains text.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_api::clipboard::has_text;
+///
+/// if has_text().await? {
+///     // enable the "Paste" button
+/// }
+/// ```
+///
+/// Requires [`allowlist > clipboard > readText`](https://tauri.app/v1/api/config#clipboardallowlistconfig.readtext) to be enabled.
+#[inline(always)]
+pub async fn has_text() -> crate::Result<bool> {
+    Ok(read_text().await?.is_some())
 }
 
 /// Writes plain text to the clipboard.
@@ -42,7 +65,7 @@ pub async fn read_text() -> crate::Result<String> {
 /// use tauri_api::clipboard::{write_text, read_text};
 ///
 /// write_text("Tauri is awesome!").await;
-/// assert_eq!(read_text().await, "Tauri is awesome!");
+/// assert_eq!(read_text().await?.as_deref(), Some("Tauri is awesome!"));
 /// ```
 /// 
 /// Requires [`allowlist > clipboard > writeText`](https://tauri.app/v1/api/config#clipboardallowlistconfig.writetext) to be enabled.
@@ -51,6 +74,51 @@ pub async fn write_text(text: &str) -> crate::Result<()> {
     Ok(inner::writeText(text).await?)
 }
 
+/// Listen to changes of the clipboard text.
+///
+/// The backend doesn't notify about clipboard changes, so the clipboard is polled every `interval`
+/// and the new text is yielded whenever it differs from the previous one. Errors while reading the clipboard are logged and skipped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use std::time::Duration;
+/// use tauri_api::clipboard::on_change;
+///
+/// let mut changes = on_change(Duration::from_millis(500));
+///
+/// while let Some(text) = changes.next().await {
+///     log::info!("Clipboard now contains {:?}", text);
+/// }
+/// ```
+///
+/// Requires [`allowlist > clipboard > readText`](https://tauri.app/v1/api/config#clipboardallowlistconfig.readtext) to be enabled.
+pub fn on_change(interval: Duration) -> impl Stream<Item = Option<String>> {
+    // `last` stays `None` until the clipboard was read successfully, so a failing first read isn't reported as a change
+    futures::stream::unfold(None, move |mut last: Option<Option<String>>| async move {
+        if last.is_none() {
+            match read_text().await {
+                Ok(text) => last = Some(text),
+                Err(err) => log::error!("Failed to read clipboard: {}", err),
+            }
+        }
+
+        loop {
+            crate::utils::sleep(interval).await;
+
+            match (read_text().await, &last) {
+                (Ok(text), Some(previous)) if text != *previous => {
+                    return Some((text.clone(), Some(text)));
+                }
+                (Ok(text), None) => last = Some(text),
+                (Ok(_), Some(_)) => {}
+                (Err(err), _) => log::error!("Failed to read clipboard: {}", err),
+            }
+        }
+    })
+}
+
 mod inner {
     use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
@@ -21,7 +21,13 @@
 
 use js_sys::Array;
 use serde::Serialize;
-use std::path::{Path, PathBuf};
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use wasm_bindgen_futures::JsFuture;
 
 use crate::utils::ArrayIterator;
 #[derive(Debug, Clone, Copy, Hash, Serialize)]
@@ -42,6 +48,8 @@ pub struct FileDialogBuilder<'a> {
     directory: bool,
     multiple: bool,
     recursive: bool,
+    #[serde(skip)]
+    starting_file_name: Option<&'a str>,
 }
 
 impl<'a> FileDialogBuilder<'a> {
@@ -56,6 +64,17 @@ impl<'a> FileDialogBuilder<'a> {
         self
     }
 
+    /// Set the file name the save dialog is prefilled with.
+    ///
+    /// The name is joined onto the [default path](Self::set_default_path) which should then point to a directory.
+    /// Only used by [`save`](Self::save).
+    ///
+    /// Creating new directories from the dialog is always allowed by the v1 backend, there is no option to disable it.
+    pub fn set_starting_file_name(&mut self, file_name: &'a str) -> &mut Self {
+        self.starting_file_name = Some(file_name);
+        self
+    }
+
     /// If directory is true, indicates that it will be read recursively later.
     /// Defines whether subdirectories will be allowed on the scope or not.
     ///
@@ -150,7 +169,7 @@ impl<'a> FileDialogBuilder<'a> {
     pub async fn pick_file(&self) -> crate::Result<Option<PathBuf>> {
         let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
 
-        Ok(serde_wasm_bindgen::from_value(raw)?)
+        crate::from_js(raw, "dialog::pick_file")
     }
 
     /// Shows the dialog to select multiple files.
@@ -173,8 +192,8 @@ impl<'a> FileDialogBuilder<'a> {
         let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
 
         if let Ok(files) = Array::try_from(raw) {
-            let files =
-                ArrayIterator::new(files).map(|raw| serde_wasm_bindgen::from_value(raw).unwrap());
+            let files = ArrayIterator::new(files)
+                .map(|raw| crate::from_js(raw, "dialog::pick_files").unwrap());
 
             Ok(Some(files))
         } else {
@@ -201,7 +220,71 @@ impl<'a> FileDialogBuilder<'a> {
 
         let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
 
-        Ok(serde_wasm_bindgen::from_value(raw)?)
+        crate::from_js(raw, "dialog::pick_folder")
+    }
+
+    /// Shows the dialog to select a single folder and lists the files inside it that match the builder's filters.
+    ///
+    /// Only direct children are listed, unless [`set_recursive`](Self::set_recursive) is enabled.
+    /// Without filters all files are listed. Extensions are compared case-insensitively.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::dialog::FileDialogBuilder;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some((folder, csv_files)) = FileDialogBuilder::new()
+    ///     .add_filter("CSV", &["csv"])
+    ///     .pick_folder_with_files()
+    ///     .await?
+    /// {
+    ///     log::info!("{} CSV files in {}", csv_files.len(), folder.display());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires the `fs` feature, [`allowlist > dialog > open`](https://tauri.app/v1/api/config#dialogallowlistconfig.open)
+    /// and [`allowlist > fs > readDir`](https://tauri.app/v1/api/config#fsallowlistconfig.readdir) to be enabled.
+    #[cfg(feature = "fs")]
+    pub async fn pick_folder_with_files(
+        &mut self,
+    ) -> crate::Result<Option<(PathBuf, Vec<PathBuf>)>> {
+        use crate::fs::{self, BaseDirectory, WalkDirOptions};
+        use futures::TryStreamExt;
+
+        let Some(folder) = self.pick_folder().await? else {
+            return Ok(None);
+        };
+
+        let mut options = WalkDirOptions::new();
+        options.set_skip_dirs(true);
+        if !self.recursive {
+            options.set_max_depth(1);
+        }
+
+        let matches = |path: &Path| {
+            self.filters.is_empty()
+                || path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| {
+                        self.filters
+                            .iter()
+                            .flat_map(|filter| filter.extensions)
+                            .any(|allowed| *allowed == "*" || allowed.eq_ignore_ascii_case(ext))
+                    })
+        };
+
+        // the picked folder is absolute, so the base directory is ignored by the backend
+        let files = fs::walk_dir(&folder, BaseDirectory::Home, &options)
+            .map_ok(|entry| entry.path)
+            .try_filter(|path| futures::future::ready(matches(path)))
+            .try_collect()
+            .await?;
+
+        Ok(Some((folder, files)))
     }
 
     /// Shows the dialog to select multiple folders.
@@ -225,8 +308,8 @@ impl<'a> FileDialogBuilder<'a> {
         let raw = inner::open(serde_wasm_bindgen::to_value(&self)?).await?;
 
         if let Ok(files) = Array::try_from(raw) {
-            let files =
-                ArrayIterator::new(files).map(|raw| serde_wasm_bindgen::from_value(raw).unwrap());
+            let files = ArrayIterator::new(files)
+                .map(|raw| crate::from_js(raw, "dialog::pick_folders").unwrap());
 
             Ok(Some(files))
         } else {
@@ -255,9 +338,21 @@ impl<'a> FileDialogBuilder<'a> {
     ///
     /// Requires [`allowlist > dialog > save`](https://tauri.app/v1/api/config#dialogallowlistconfig.save) to be enabled.
     pub async fn save(&self) -> crate::Result<Option<PathBuf>> {
-        let raw = inner::save(serde_wasm_bindgen::to_value(&self)?).await?;
+        let options = serde_wasm_bindgen::to_value(&self)?;
+
+        if let Some(file_name) = self.starting_file_name {
+            let default_path = self.default_path.unwrap_or(Path::new("")).join(file_name);
+
+            js_sys::Reflect::set(
+                &options,
+                &"defaultPath".into(),
+                &serde_wasm_bindgen::to_value(&default_path)?,
+            )?;
+        }
+
+        let raw = inner::save(options).await?;
 
-        Ok(serde_wasm_bindgen::from_value(raw)?)
+        crate::from_js(raw, "dialog::save")
     }
 }
 
@@ -355,7 +450,7 @@ impl<'a> MessageDialogBuilder<'a> {
     pub async fn ask(&self, message: &str) -> crate::Result<bool> {
         let raw = inner::ask(message, serde_wasm_bindgen::to_value(&self)?).await?;
 
-        Ok(serde_wasm_bindgen::from_value(raw)?)
+        crate::from_js(raw, "dialog::ask")
     }
 
     /// Shows a question dialog with `Ok` and `Cancel` buttons.
@@ -375,7 +470,49 @@ impl<'a> MessageDialogBuilder<'a> {
     pub async fn confirm(&self, message: &str) -> crate::Result<bool> {
         let raw = inner::confirm(message, serde_wasm_bindgen::to_value(&self)?).await?;
 
-        Ok(serde_wasm_bindgen::from_value(raw)?)
+        crate::from_js(raw, "dialog::confirm")
+    }
+
+    /// Shows a message dialog with an `Ok` button without waiting for it to be dismissed.
+    ///
+    /// The dialog is opened right away, the returned [`MessageDialogHandle`] resolves once it is dismissed and can be awaited or simply dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::dialog::{MessageDialogBuilder, MessageDialogKind};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let dismissed = MessageDialogBuilder::new()
+    ///     .set_kind(MessageDialogKind::Error)
+    ///     .message_detached("Upload failed")?;
+    ///
+    /// // keep going, and optionally wait for the user later on
+    /// dismissed.await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires [`allowlist > dialog > message`](https://tauri.app/v1/api/config#dialogallowlistconfig.message) to be enabled.
+    pub fn message_detached(&self, message: &str) -> crate::Result<MessageDialogHandle> {
+        let promise = inner::message_detached(message, serde_wasm_bindgen::to_value(&self)?);
+
+        Ok(MessageDialogHandle(JsFuture::from(promise)))
+    }
+}
+
+/// A message dialog opened with [`MessageDialogBuilder::message_detached`], resolves once the dialog is dismissed.
+///
+/// Dropping the handle does not close the dialog.
+pub struct MessageDialogHandle(JsFuture);
+
+impl Future for MessageDialogHandle {
+    type Output = crate::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0)
+            .poll(cx)
+            .map(|res| res.map(|_| ()).map_err(Into::into))
     }
 }
 
@@ -392,6 +529,8 @@ mod inner {
         pub async fn open(options: JsValue) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn message(message: &str, option: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(js_name = message)]
+        pub fn message_detached(message: &str, option: JsValue) -> js_sys::Promise;
         #[wasm_bindgen(catch)]
         pub async fn save(options: JsValue) -> Result<JsValue, JsValue>;
     }
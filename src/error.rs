@@ -7,12 +7,41 @@ pub enum Error {
     Command(String),
     #[error("Failed to parse JSON: {0}")]
     Serde(String),
+    #[cfg(feature = "app")]
+    #[error("Invalid version requirement: {0}")]
+    InvalidVersionRequirement(String),
+    #[cfg(feature = "app")]
+    #[error("Tauri {found} does not satisfy the version requirement {required:?}")]
+    UnsupportedTauriVersion { required: String, found: String },
     #[cfg(any(feature = "event", feature = "window"))]
     #[error("Oneshot cancelled: {0}")]
     OneshotCanceled(#[from] futures::channel::oneshot::Canceled),
-    #[cfg(feature = "fs")]
+    #[cfg(any(feature = "fs", feature = "tauri"))]
     #[error("Could not convert path to string")]
     Utf8(PathBuf),
+    #[cfg(feature = "global_shortcut")]
+    #[error("Invalid accelerator {0:?}")]
+    InvalidAccelerator(String),
+    #[cfg(feature = "glob")]
+    #[error("Invalid glob pattern: {0}")]
+    InvalidGlob(String),
+    #[cfg(feature = "shell")]
+    #[error("Invalid path or URL {0:?}")]
+    InvalidPath(String),
+    #[cfg(feature = "shell")]
+    #[error("Opening {url:?} is not allowed: scheme {scheme:?} is not in the allowed scopes")]
+    SchemeNotAllowed { url: String, scheme: String },
+    #[cfg(feature = "tauri")]
+    #[error("Invocation of command {0:?} was aborted")]
+    Aborted(String),
+    #[cfg(feature = "window")]
+    #[error("Failed to create window: {0}")]
+    CreateWindow(#[from] crate::window::CreateWindowError),
+    #[cfg(feature = "window")]
+    #[error(
+        "Invalid label {0:?}: only alphanumeric characters and `-`, `/`, `:` and `_` are allowed"
+    )]
+    InvalidLabel(String),
 }
 
 impl From<serde_wasm_bindgen::Error> for Error {
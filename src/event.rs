@@ -2,10 +2,17 @@
 
 use futures::{
     channel::{mpsc, oneshot},
+    future::AbortHandle,
     Future, FutureExt, Stream, StreamExt,
 };
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::fmt::Debug;
+use std::{
+    cell::{Cell, RefCell},
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    rc::Rc,
+    time::Duration,
+};
 use wasm_bindgen::{prelude::Closure, JsValue};
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -14,13 +21,108 @@ pub struct Event<T> {
     /// Event name
     pub event: String,
     /// Event identifier used to unlisten
-    pub id: f32,
+    pub id: EventId,
     /// Event payload
     pub payload: T,
     /// The label of the window that emitted this event
     pub window_label: Option<String>,
 }
 
+/// Identifier of the listener an [`Event`] was delivered to.
+///
+/// Events that were created locally, e.g. by wrappers that translate DOM events, carry [`EventId::SYNTHETIC`]
+/// since there is no backend listener they belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EventId(u32);
+
+impl EventId {
+    /// Marker id for events that were not delivered by the backend.
+    pub const SYNTHETIC: Self = Self(u32::MAX);
+
+    /// Creates an id from the raw value used by the JS API.
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the raw value used by the JS API.
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+
+    /// Whether this is the [`EventId::SYNTHETIC`] marker.
+    pub const fn is_synthetic(self) -> bool {
+        self.0 == Self::SYNTHETIC.0
+    }
+}
+
+impl std::fmt::Display for EventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_synthetic() {
+            f.write_str("synthetic")
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// Removes the backend listener with the given id for `event`.
+///
+/// This is an escape hatch mirroring the JS `unlisten` helper, e.g. for listeners registered from JavaScript.
+/// Listeners created through this crate are removed automatically when their stream is dropped
+/// and should not be removed with this function.
+///
+/// Unlistening a [synthetic](EventId::SYNTHETIC) id is a no-op.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::event::{listen, unlisten_by_id};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut events = listen::<String>("js-owned-event").await?;
+///
+/// if let Some(event) = events.next().await {
+///     unlisten_by_id(&event.event, event.id).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn unlisten_by_id(event: &str, id: EventId) -> crate::Result<()> {
+    if id.is_synthetic() {
+        return Ok(());
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Message<'a> {
+        cmd: &'a str,
+        event: &'a str,
+        event_id: EventId,
+    }
+
+    #[derive(Serialize)]
+    struct Args<'a> {
+        #[serde(rename = "__tauriModule")]
+        tauri_module: &'a str,
+        message: Message<'a>,
+    }
+
+    let args = Args {
+        tauri_module: "Event",
+        message: Message {
+            cmd: "unlisten",
+            event,
+            event_id: id,
+        },
+    };
+
+    inner::invoke("tauri", serde_wasm_bindgen::to_value(&args)?).await?;
+
+    Ok(())
+}
+
 /// Emits an event to the backend.
 ///
 /// # Example
@@ -38,6 +140,8 @@ pub struct Event<T> {
 /// emit("frontend-loaded", &Payload { logged_in: true, token: "authToken" }).await;
 /// ```
 ///
+/// Unit payloads like `()` or `None` are sent as `undefined`, so the backend receives no payload instead of `null`.
+///
 /// @param event Event name. Must include only alphanumeric characters, `-`, `/`, `:` and `_`.
 #[inline(always)]
 pub async fn emit<T: Serialize>(event: &str, payload: &T) -> crate::Result<()> {
@@ -46,6 +150,159 @@ pub async fn emit<T: Serialize>(event: &str, payload: &T) -> crate::Result<()> {
     Ok(())
 }
 
+/// Emits an event without a payload to the backend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::event::emit_empty;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// emit_empty("frontend-loaded").await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// @param event Event name. Must include only alphanumeric characters, `-`, `/`, `:` and `_`.
+#[inline(always)]
+pub async fn emit_empty(event: &str) -> crate::Result<()> {
+    inner::emit(event, JsValue::UNDEFINED).await?;
+
+    Ok(())
+}
+
+/// Emits multiple events to the backend.
+///
+/// The v1 backend has no batch command, so the events are sent concurrently in the given order and this resolves once all of them were delivered.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::event::emit_batch;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// emit_batch(&[("progress", 50), ("progress", 100)]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn emit_batch<T: Serialize>(events: &[(&str, T)]) -> crate::Result<()> {
+    futures::future::try_join_all(events.iter().map(|(event, payload)| emit(event, payload)))
+        .await?;
+
+    Ok(())
+}
+
+/// Emits an event at most once per interval, dropping the events emitted in between.
+///
+/// Useful for forwarding high-frequency UI events like cursor movement or scrolling to the backend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use tauri_sys::event::Throttler;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut throttler = Throttler::new("cursor-moved", Duration::from_millis(50));
+///
+/// // called from a `mousemove` handler
+/// throttler.emit(&(10, 20)).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Throttler {
+    event: String,
+    interval: Duration,
+    last_emit: Option<f64>,
+}
+
+impl Throttler {
+    pub fn new(event: impl Into<String>, interval: Duration) -> Self {
+        Self {
+            event: event.into(),
+            interval,
+            last_emit: None,
+        }
+    }
+
+    /// Emits the event if the interval has passed since the last emitted event.
+    ///
+    /// Returns whether the event was emitted.
+    pub async fn emit<T: Serialize>(&mut self, payload: &T) -> crate::Result<bool> {
+        let now = js_sys::Date::now();
+
+        if let Some(last_emit) = self.last_emit {
+            if now - last_emit < self.interval.as_millis() as f64 {
+                return Ok(false);
+            }
+        }
+
+        self.last_emit = Some(now);
+        emit(&self.event, payload).await?;
+
+        Ok(true)
+    }
+}
+
+/// Emits an event once no new payload has been submitted for the given delay, only the latest payload is sent.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use tauri_sys::event::Debouncer;
+///
+/// let debouncer = Debouncer::new("search", Duration::from_millis(300));
+///
+/// // called from an `input` handler, only the last query is emitted
+/// debouncer.emit("tau".to_string());
+/// debouncer.emit("tauri".to_string());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Debouncer {
+    event: Rc<str>,
+    delay: Duration,
+    generation: Rc<Cell<u64>>,
+}
+
+impl Debouncer {
+    pub fn new(event: impl AsRef<str>, delay: Duration) -> Self {
+        Self {
+            event: Rc::from(event.as_ref()),
+            delay,
+            generation: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Schedules `payload` to be emitted after the delay, replacing any payload that is still pending.
+    ///
+    /// Errors while emitting are logged.
+    pub fn emit<T: Serialize + 'static>(&self, payload: T) {
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+
+        let current = self.generation.clone();
+        let event = self.event.clone();
+        let delay = self.delay;
+
+        wasm_bindgen_futures::spawn_local(async move {
+            crate::utils::sleep(delay).await;
+
+            if current.get() == generation {
+                if let Err(err) = emit(&event, &payload).await {
+                    log::error!("Failed to emit debounced event {}: {}", event, err);
+                }
+            }
+        });
+    }
+
+    /// Drops the pending payload, if any.
+    pub fn cancel(&self) {
+        self.generation.set(self.generation.get().wrapping_add(1));
+    }
+}
+
 /// Listen to an event from the backend.
 /// 
 /// The returned Future will automatically clean up it's underlying event listener when dropped, so no manual unlisten function needs to be called.
@@ -67,13 +324,602 @@ pub async fn emit<T: Serialize>(event: &str, payload: &T) -> crate::Result<()> {
 pub async fn listen<T>(event: &str) -> crate::Result<impl Stream<Item = Event<T>>>
 where
     T: DeserializeOwned + 'static,
+{
+    listen_on(&Global, event).await
+}
+
+/// Listen to a piece of backend state: yields an initial snapshot followed by the payload of every `event`.
+///
+/// The listener is registered before `fetch_initial` is called, so updates emitted while the snapshot is fetched are not lost.
+/// They are yielded after the snapshot.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::{event::listen_state, tauri::invoke};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut counter = listen_state::<u32, _, _>("counter-changed", || invoke("get_counter", &())).await?;
+///
+/// while let Some(value) = counter.next().await {
+///     log::info!("counter is {}", value);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn listen_state<T, F, Fut>(
+    event: &str,
+    fetch_initial: F,
+) -> crate::Result<impl Stream<Item = T>>
+where
+    T: DeserializeOwned + 'static,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = crate::Result<T>>,
+{
+    let updates = listen_on::<_, T>(&Global, event).await?;
+    let initial = fetch_initial().await?;
+
+    Ok(futures::stream::once(futures::future::ready(initial))
+        .chain(updates.map(|event| event.payload)))
+}
+
+/// Listen to an event, replaying up to `capacity` of the most recent events that were emitted before the stream was created.
+///
+/// The first call for an `event` registers a listener that keeps buffering for the lifetime of the app,
+/// or until [`stop_buffering`] is called. Calling this early, e.g. during startup, makes sure streams created later
+/// don't miss events that were emitted in the meantime. The buffer keeps the largest capacity requested so far.
+///
+/// If registering that listener fails, the error is returned and streams created for `event` in the meantime end.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::event::listen_buffered;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // during startup
+/// drop(listen_buffered::<String>("backend-ready", 1).await?);
+///
+/// // later, the event is replayed even if it was emitted in between
+/// let mut ready = listen_buffered::<String>("backend-ready", 1).await?;
+/// let event = ready.next().await;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn listen_buffered<T>(
+    event: &str,
+    capacity: usize,
+) -> crate::Result<impl Stream<Item = Event<T>>>
+where
+    T: DeserializeOwned + 'static,
+{
+    let existing = REPLAY_BUFFERS.with(|buffers| buffers.borrow().get(event).cloned());
+    let buffer = match existing {
+        Some(buffer) => buffer,
+        None => {
+            let buffer = Rc::new(RefCell::new(ReplayBuffer {
+                capacity,
+                events: VecDeque::with_capacity(capacity),
+                subscribers: Vec::new(),
+                listener: None,
+            }));
+            // registered before awaiting, so concurrent callers share this buffer
+            REPLAY_BUFFERS.with(|buffers| {
+                buffers
+                    .borrow_mut()
+                    .insert(event.to_string(), buffer.clone())
+            });
+
+            let shared = buffer.clone();
+            let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+                shared.borrow_mut().push(raw);
+            });
+
+            match Global.listen_raw(event, &closure).await {
+                Ok(unlisten) => {
+                    let listener = BufferListener {
+                        _closure: closure,
+                        unlisten: js_sys::Function::from(unlisten),
+                    };
+
+                    // `stop_buffering` may have been called while the listener was being registered
+                    let current = REPLAY_BUFFERS.with(|buffers| {
+                        buffers
+                            .borrow()
+                            .get(event)
+                            .is_some_and(|current| Rc::ptr_eq(current, &buffer))
+                    });
+                    if current {
+                        buffer.borrow_mut().listener = Some(listener);
+                    } else {
+                        listener.unlisten(event);
+                    }
+                }
+                Err(err) => {
+                    REPLAY_BUFFERS.with(|buffers| buffers.borrow_mut().remove(event));
+                    // ends the streams of callers that joined while the listener was being registered
+                    buffer.borrow_mut().subscribers.clear();
+                    return Err(err.into());
+                }
+            }
+
+            buffer
+        }
+    };
+
+    let (tx, rx) = mpsc::unbounded();
+    let replay: Vec<_> = {
+        let mut buffer = buffer.borrow_mut();
+        buffer.capacity = buffer.capacity.max(capacity);
+        buffer.subscribers.push(tx);
+        buffer.events.iter().cloned().collect()
+    };
+
+    let context = event.to_string();
+    Ok(futures::stream::iter(replay)
+        .chain(rx)
+        .filter_map(move |raw| futures::future::ready(crate::closure_arg(raw, &context))))
+}
+
+/// Stops buffering `event` and removes the listener registered by [`listen_buffered`].
+///
+/// Streams created by [`listen_buffered`] for this event end.
+pub fn stop_buffering(event: &str) {
+    let Some(buffer) = REPLAY_BUFFERS.with(|buffers| buffers.borrow_mut().remove(event)) else {
+        return;
+    };
+
+    let listener = {
+        let mut buffer = buffer.borrow_mut();
+        buffer.subscribers.clear();
+        buffer.listener.take()
+    };
+    // the closure holds a reference to the buffer, dropping it after unlistening breaks the cycle
+    if let Some(listener) = listener {
+        listener.unlisten(event);
+    }
+}
+
+thread_local! {
+    static REPLAY_BUFFERS: RefCell<HashMap<String, Rc<RefCell<ReplayBuffer>>>> = RefCell::default();
+}
+
+struct ReplayBuffer {
+    capacity: usize,
+    events: VecDeque<JsValue>,
+    subscribers: Vec<mpsc::UnboundedSender<JsValue>>,
+    listener: Option<BufferListener>,
+}
+
+struct BufferListener {
+    _closure: Closure<dyn FnMut(JsValue)>,
+    unlisten: js_sys::Function,
+}
+
+impl BufferListener {
+    fn unlisten(self, event: &str) {
+        if let Err(err) = self.unlisten.call0(&JsValue::NULL) {
+            log::error!("Failed to unlisten buffered event {}: {:?}", event, err);
+        }
+    }
+}
+
+impl ReplayBuffer {
+    fn push(&mut self, raw: JsValue) {
+        self.subscribers
+            .retain(|tx| tx.unbounded_send(raw.clone()).is_ok());
+
+        if self.capacity == 0 {
+            return;
+        }
+        while self.events.len() >= self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(raw);
+    }
+}
+
+/// Relays the backend event `event` to `target` as a DOM [`CustomEvent`](https://developer.mozilla.org/en-US/docs/Web/API/CustomEvent) of the same name.
+///
+/// The event payload is available as the `detail` of the `CustomEvent`, `target` is any DOM `EventTarget`, e.g. `window` or an element.
+/// Relaying stops when the returned [`DomForward`] is dropped.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::event::forward_to_dom;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // JS components can now `window.addEventListener("download-progress", ...)`
+/// let forward = forward_to_dom("download-progress", &js_sys::global()).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn forward_to_dom(event: &str, target: &JsValue) -> crate::Result<DomForward> {
+    let target = target.clone();
+    let name = event.to_string();
+
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+        let dispatched = js_sys::Reflect::get(&raw, &JsValue::from_str("payload"))
+            .and_then(|detail| dispatch_custom_event(&target, &name, &detail));
+
+        if let Err(err) = dispatched {
+            log::error!("Failed to dispatch DOM event {}: {:?}", name, err);
+        }
+    });
+    let unlisten = Global.listen_raw(event, &closure).await?;
+
+    Ok(DomForward {
+        _closure: closure,
+        cleanup: DomCleanup::Unlisten(js_sys::Function::from(unlisten)),
+    })
+}
+
+/// Relays the DOM event `dom_event` dispatched on `target` to the backend as `event`.
+///
+/// For `CustomEvent`s the `detail` is emitted as the payload, other DOM events are emitted without payload.
+/// Relaying stops when the returned [`DomForward`] is dropped.
+///
+/// Returns an error if `target` is not a DOM `EventTarget`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::event::forward_from_dom;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let forward = forward_from_dom("cart-updated", &js_sys::global(), "cart-updated")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn forward_from_dom(
+    dom_event: &str,
+    target: &JsValue,
+    event: &str,
+) -> crate::Result<DomForward> {
+    let name = event.to_string();
+
+    let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+        let detail = custom_event_detail(&raw);
+        let name = name.clone();
+
+        wasm_bindgen_futures::spawn_local(async move {
+            if let Err(err) = inner::emit(&name, detail).await {
+                log::error!("Failed to emit event {}: {:?}", name, err);
+            }
+        });
+    });
+    call_dom_method(target, "addEventListener", dom_event, &closure)?;
+
+    Ok(DomForward {
+        _closure: closure,
+        cleanup: DomCleanup::RemoveListener {
+            target: target.clone(),
+            event: dom_event.to_string(),
+        },
+    })
+}
+
+/// Keeps an event relay created by [`forward_to_dom`] or [`forward_from_dom`] alive, relaying stops when this is dropped.
+pub struct DomForward {
+    _closure: Closure<dyn FnMut(JsValue)>,
+    cleanup: DomCleanup,
+}
+
+enum DomCleanup {
+    Unlisten(js_sys::Function),
+    RemoveListener { target: JsValue, event: String },
+}
+
+impl Drop for DomForward {
+    fn drop(&mut self) {
+        log::debug!("Stopping DOM event relay");
+        let stopped = match &self.cleanup {
+            DomCleanup::Unlisten(unlisten) => unlisten
+                .call0(&JsValue::NULL)
+                .map(drop)
+                .map_err(crate::Error::from),
+            DomCleanup::RemoveListener { target, event } => {
+                call_dom_method(target, "removeEventListener", event, &self._closure)
+            }
+        };
+
+        if let Err(err) = stopped {
+            log::error!("Failed to stop DOM event relay: {}", err);
+        }
+    }
+}
+
+fn call_dom_method(
+    target: &JsValue,
+    method: &str,
+    event: &str,
+    closure: &Closure<dyn FnMut(JsValue)>,
+) -> crate::Result<()> {
+    use wasm_bindgen::JsCast;
+
+    let method = js_sys::Reflect::get(target, &JsValue::from_str(method))?
+        .dyn_into::<js_sys::Function>()
+        .map_err(|_| crate::Error::Command(format!("{:?} is not an EventTarget", target)))?;
+
+    method.call2(target, &JsValue::from_str(event), closure.as_ref())?;
+
+    Ok(())
+}
+
+/// The `detail` of a `CustomEvent`, other events like `UIEvent` have a `detail` with a different meaning.
+fn custom_event_detail(event: &JsValue) -> JsValue {
+    use wasm_bindgen::JsCast;
+
+    let prototype = js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("CustomEvent"))
+        .and_then(|constructor| {
+            js_sys::Reflect::get(&constructor, &JsValue::from_str("prototype"))
+        });
+    let is_custom_event = prototype.is_ok_and(|prototype| {
+        prototype
+            .unchecked_into::<js_sys::Object>()
+            .is_prototype_of(event)
+    });
+
+    if is_custom_event {
+        js_sys::Reflect::get(event, &JsValue::from_str("detail")).unwrap_or(JsValue::UNDEFINED)
+    } else {
+        JsValue::UNDEFINED
+    }
+}
+
+fn dispatch_custom_event(target: &JsValue, name: &str, detail: &JsValue) -> Result<(), JsValue> {
+    use wasm_bindgen::JsCast;
+
+    let constructor: js_sys::Function =
+        js_sys::Reflect::get(&js_sys::global(), &JsValue::from_str("CustomEvent"))?
+            .unchecked_into();
+
+    let init = js_sys::Object::new();
+    js_sys::Reflect::set(&init, &JsValue::from_str("detail"), detail)?;
+
+    let event = js_sys::Reflect::construct(
+        &constructor,
+        &js_sys::Array::of2(&JsValue::from_str(name), &init),
+    )?;
+
+    let dispatch: js_sys::Function =
+        js_sys::Reflect::get(target, &JsValue::from_str("dispatchEvent"))?.unchecked_into();
+    dispatch.call1(target, &event)?;
+
+    Ok(())
+}
+
+/// Combinators for streams of [`Event`]s, e.g. the ones returned by [`listen`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::event::{listen, TauriStreamExt};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (progress, abort_handle) = listen::<u32>("progress")
+///     .await?
+///     .filter_event(|event| event.window_label.as_deref() == Some("main"))
+///     .map_payload(|percent| percent as f32 / 100.0)
+///     .abortable();
+/// let mut progress = progress.payloads();
+///
+/// while let Some(fraction) = progress.next().await {
+///     log::info!("{:.0}% done", fraction * 100.0);
+/// }
+///
+/// // `abort_handle.abort()` ends the stream from another task
+/// # Ok(())
+/// # }
+/// ```
+pub trait TauriStreamExt<T>: Stream<Item = Event<T>> + Sized {
+    /// Strips the [`Event`] wrapper, yielding only the payloads.
+    fn payloads(self) -> impl Stream<Item = T> {
+        self.map(|event| event.payload)
+    }
+
+    /// Maps the payload of every event, keeping the rest of the event intact.
+    fn map_payload<U, F>(self, mut f: F) -> impl Stream<Item = Event<U>>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(move |event| Event {
+            event: event.event,
+            id: event.id,
+            payload: f(event.payload),
+            window_label: event.window_label,
+        })
+    }
+
+    /// Only yields the events for which `predicate` returns `true`.
+    fn filter_event<F>(self, mut predicate: F) -> impl Stream<Item = Event<T>>
+    where
+        F: FnMut(&Event<T>) -> bool,
+    {
+        self.filter(move |event| futures::future::ready(predicate(event)))
+    }
+
+    /// Shorthand for [`futures::stream::abortable`], the handle behaves like the JS `unlisten` function.
+    fn abortable(self) -> (futures::stream::Abortable<Self>, AbortHandle) {
+        futures::stream::abortable(self)
+    }
+}
+
+impl<T, S: Stream<Item = Event<T>>> TauriStreamExt<T> for S {}
+
+/// A group of event listeners that are stopped together.
+///
+/// Useful for UI components that start several listeners when they are mounted and stop all of them when unmounted.
+/// Closing or dropping the scope unlistens every event and ends all streams created through it.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::event::EventScope;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut scope = EventScope::new();
+/// let mut progress = scope.listen::<u32>("progress").await?;
+/// let mut errors = scope.listen::<String>("error").await?;
+///
+/// // when the component is unmounted
+/// scope.close();
+///
+/// assert!(progress.next().await.is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct EventScope {
+    listeners: Vec<Box<dyn FnOnce()>>,
+}
+
+impl EventScope {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Listen to an event from the backend for as long as the scope is open, see [`listen`].
+    pub async fn listen<T>(&mut self, event: &str) -> crate::Result<impl Stream<Item = Event<T>>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let listen = listen_on(&Global, event).await?;
+        let scoped = Rc::new(RefCell::new(ScopedListen {
+            listen: Some(listen),
+            waker: None,
+        }));
+
+        // the stream owns the listener, so dropping it early still unlistens right away
+        let weak = Rc::downgrade(&scoped);
+        self.listeners.push(Box::new(move || {
+            if let Some(scoped) = weak.upgrade() {
+                scoped.borrow_mut().close();
+            }
+        }));
+
+        Ok(futures::stream::poll_fn(move |cx| {
+            scoped.borrow_mut().poll_next(cx)
+        }))
+    }
+
+    /// The number of listeners in this scope.
+    pub fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    /// Whether no listeners have been added to this scope yet.
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+
+    /// Unlistens all events of this scope and ends their streams.
+    pub fn close(self) {}
+}
+
+impl Debug for EventScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventScope")
+            .field("listeners", &self.listeners.len())
+            .finish()
+    }
+}
+
+impl Drop for EventScope {
+    fn drop(&mut self) {
+        log::debug!(
+            "Closing event scope with {} listeners",
+            self.listeners.len()
+        );
+        for close in self.listeners.drain(..) {
+            close();
+        }
+    }
+}
+
+struct ScopedListen<T> {
+    listen: Option<Listen<Event<T>>>,
+    waker: Option<std::task::Waker>,
+}
+
+impl<T> ScopedListen<T> {
+    fn poll_next(&mut self, cx: &mut std::task::Context<'_>) -> std::task::Poll<Option<Event<T>>> {
+        match &mut self.listen {
+            Some(listen) => {
+                self.waker = Some(cx.waker().clone());
+                listen.poll_next_unpin(cx)
+            }
+            None => std::task::Poll::Ready(None),
+        }
+    }
+
+    /// Drops the listener, which unlistens the event, and wakes the stream so it ends.
+    fn close(&mut self) {
+        self.listen = None;
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// Something events can be listened to on, e.g. the global event bus or a single window.
+///
+/// This lets [`listen`]/[`once`] and the window-scoped variants share one code path.
+pub(crate) trait EventSource {
+    async fn listen_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue>;
+
+    async fn once_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue>;
+}
+
+/// The global, app-wide event bus.
+pub(crate) struct Global;
+
+impl EventSource for Global {
+    async fn listen_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue> {
+        inner::listen(event, handler).await
+    }
+
+    async fn once_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue> {
+        inner::once(event, handler).await
+    }
+}
+
+pub(crate) async fn listen_on<S, T>(source: &S, event: &str) -> crate::Result<Listen<Event<T>>>
+where
+    S: EventSource,
+    T: DeserializeOwned + 'static,
 {
     let (tx, rx) = mpsc::unbounded::<Event<T>>();
 
+    let context = event.to_string();
     let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
-        let _ = tx.unbounded_send(serde_wasm_bindgen::from_value(raw).unwrap());
+        if let Some(event) = crate::closure_arg(raw, &context) {
+            let _ = tx.unbounded_send(event);
+        }
     });
-    let unlisten = inner::listen(event, &closure).await?;
+    let unlisten = source.listen_raw(event, &closure).await?;
     closure.forget();
 
     Ok(Listen {
@@ -82,6 +928,30 @@ where
     })
 }
 
+pub(crate) async fn once_on<S, T>(source: &S, event: &str) -> crate::Result<Event<T>>
+where
+    S: EventSource,
+    T: DeserializeOwned + 'static,
+{
+    let (tx, rx) = oneshot::channel::<Event<T>>();
+
+    let context = event.to_string();
+    let closure: Closure<dyn FnMut(JsValue)> = Closure::once(move |raw| {
+        if let Some(event) = crate::closure_arg(raw, &context) {
+            let _ = tx.send(event);
+        }
+    });
+    let unlisten = source.once_raw(event, &closure).await?;
+    closure.forget();
+
+    let fut = Once {
+        rx,
+        unlisten: js_sys::Function::from(unlisten),
+    };
+
+    fut.await
+}
+
 pub(crate) struct Listen<T> {
     pub rx: mpsc::UnboundedReceiver<T>,
     pub unlisten: js_sys::Function,
@@ -135,20 +1005,7 @@ pub async fn once<T>(event: &str) -> crate::Result<Event<T>>
 where
     T: DeserializeOwned + 'static,
 {
-    let (tx, rx) = oneshot::channel::<Event<T>>();
-
-    let closure: Closure<dyn FnMut(JsValue)> = Closure::once(move |raw| {
-        let _ = tx.send(serde_wasm_bindgen::from_value(raw).unwrap());
-    });
-    let unlisten = inner::once(event, &closure).await?;
-    closure.forget();
-
-    let fut = Once {
-        rx,
-        unlisten: js_sys::Function::from(unlisten),
-    };
-
-    fut.await
+    once_on(&Global, event).await
 }
 
 pub(crate) struct Once<T> {
@@ -196,4 +1053,10 @@ mod inner {
             handler: &Closure<dyn FnMut(JsValue)>,
         ) -> Result<JsValue, JsValue>;
     }
+
+    #[wasm_bindgen(module = "/src/tauri.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+    }
 }
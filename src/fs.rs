@@ -23,9 +23,11 @@
 //! ```
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 use crate::Error;
+use futures::Stream;
 use js_sys::ArrayBuffer;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_repr::*;
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
 use std::str;
 
@@ -110,7 +112,7 @@ pub async fn copy_file(source: &Path, destination: &Path, dir: BaseDirectory) ->
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::copy_file")
 }
 
 /// Creates a directory.
@@ -192,7 +194,45 @@ pub async fn exists(path: &Path, dir: BaseDirectory) -> crate::Result<bool> {
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::exists")
+}
+
+/// Checks whether `path` is inside the configured `fs` scope, so UI for paths the app can't access can be disabled up front.
+///
+/// v1 has a single scope for all file system operations, the individual operations still need to be enabled in the allowlist.
+/// The check is done by probing the path with [`exists`], which doesn't touch the file.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::fs;
+///
+/// let can_open = fs::is_allowed(path, BaseDirectory::Home).await?;
+/// ```
+///
+/// Requires [`allowlist > fs > exists`](https://tauri.app/v1/api/js/fs) to be enabled.
+pub async fn is_allowed(path: &Path, dir: BaseDirectory) -> crate::Result<bool> {
+    let Some(path) = path.to_str() else {
+        return Err(Error::Utf8(path.to_path_buf()));
+    };
+
+    let res = inner::exists(
+        path,
+        serde_wasm_bindgen::to_value(&FsOptions { dir: Some(dir) })?,
+    )
+    .await;
+
+    match res {
+        Ok(_) => Ok(true),
+        Err(err)
+            if err
+                .as_string()
+                .is_some_and(|msg| msg.contains("not allowed on the configured scope")) =>
+        {
+            Ok(false)
+        }
+        Err(err) => Err(err.into()),
+    }
 }
 
 /// Reads a file as a byte array.
@@ -217,7 +257,7 @@ pub async fn read_binary_file(path: &Path, dir: BaseDirectory) -> crate::Result<
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::read_binary_file")
 }
 
 /// List directory files.
@@ -246,7 +286,7 @@ pub async fn read_dir(path: &Path, dir: BaseDirectory) -> crate::Result<Vec<File
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::read_dir")
 }
 
 /// List directory files recursively.
@@ -275,7 +315,169 @@ pub async fn read_dir_all(path: &Path, dir: BaseDirectory) -> crate::Result<Vec<
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::read_dir_all")
+}
+
+/// An entry yielded by [`walk_dir`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct WalkEntry {
+    /// The full path of the entry.
+    pub path: PathBuf,
+    /// The path of the entry relative to the walked directory.
+    pub relative_path: PathBuf,
+    /// The name of the entry, `None` if it can't be represented as UTF-8.
+    pub name: Option<String>,
+    /// Whether the entry is a directory.
+    pub is_dir: bool,
+    /// How deep the entry is below the walked directory, direct children have a depth of `1`.
+    pub depth: usize,
+}
+
+/// Options for [`walk_dir`].
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct WalkDirOptions {
+    max_depth: Option<usize>,
+    skip_dirs: bool,
+}
+
+impl WalkDirOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Don't descend further than `max_depth` levels below the walked directory.
+    pub fn set_max_depth(&mut self, max_depth: usize) -> &mut Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Only yield files, directories are still descended into.
+    pub fn set_skip_dirs(&mut self, skip_dirs: bool) -> &mut Self {
+        self.skip_dirs = skip_dirs;
+        self
+    }
+}
+
+struct Walker {
+    base_dir: BaseDirectory,
+    options: WalkDirOptions,
+    dirs: Vec<(PathBuf, PathBuf, usize)>,
+    pending: VecDeque<WalkEntry>,
+}
+
+/// Recursively walks a directory, lazily reading subdirectories as the stream is polled.
+///
+/// Unlike [`read_dir_all`] which reads the whole tree in one call, only one directory is read at a time.
+/// Errors reading a directory are yielded and the walk continues with the remaining directories.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::fs::{self, BaseDirectory, WalkDirOptions};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut entries = fs::walk_dir(Path::new("projects"), BaseDirectory::Document, WalkDirOptions::new().set_skip_dirs(true));
+///
+/// while let Some(entry) = entries.next().await {
+///     log::info!("{}", entry?.path.display());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`allowlist > fs > readDir`](https://tauri.app/v1/api/js/fs) to be enabled.
+pub fn walk_dir(
+    path: &Path,
+    dir: BaseDirectory,
+    options: &WalkDirOptions,
+) -> impl Stream<Item = crate::Result<WalkEntry>> {
+    let walker = Walker {
+        base_dir: dir,
+        options: options.clone(),
+        dirs: vec![(path.to_path_buf(), PathBuf::new(), 0)],
+        pending: VecDeque::new(),
+    };
+
+    futures::stream::unfold(walker, |mut walker| async move {
+        loop {
+            if let Some(entry) = walker.pending.pop_front() {
+                let descend = match walker.options.max_depth {
+                    Some(max_depth) => entry.depth < max_depth,
+                    None => true,
+                };
+
+                if entry.is_dir && descend {
+                    walker.dirs.push((
+                        entry.path.clone(),
+                        entry.relative_path.clone(),
+                        entry.depth,
+                    ));
+                }
+
+                if entry.is_dir && walker.options.skip_dirs {
+                    continue;
+                }
+
+                return Some((Ok(entry), walker));
+            }
+
+            let (path, relative_path, depth) = walker.dirs.pop()?;
+
+            match read_dir(&path, walker.base_dir.clone()).await {
+                Ok(entries) => {
+                    walker.pending.extend(entries.into_iter().map(|entry| {
+                        WalkEntry {
+                            is_dir: entry.children.is_some(),
+                            relative_path: relative_path
+                                .join(entry.path.file_name().unwrap_or(entry.path.as_os_str())),
+                            path: entry.path,
+                            name: entry.name,
+                            depth: depth + 1,
+                        }
+                    }))
+                }
+                Err(err) => return Some((Err(err), walker)),
+            }
+        }
+    })
+}
+
+/// Lists all files below `path` whose path relative to `path` matches the glob `pattern`, e.g. `**/*.md`.
+///
+/// Directories are matched as well if the pattern matches them, a trailing `/**` can be used to match everything inside one.
+/// See [`globset`] for the supported syntax.
+///
+/// Requires the `glob` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::fs::{self, BaseDirectory};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let notes = fs::glob("**/*.md", Path::new("notes"), BaseDirectory::Document).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`allowlist > fs > readDir`](https://tauri.app/v1/api/js/fs) to be enabled.
+#[cfg(feature = "glob")]
+pub async fn glob(pattern: &str, path: &Path, dir: BaseDirectory) -> crate::Result<Vec<PathBuf>> {
+    use futures::TryStreamExt;
+
+    let matcher = globset::Glob::new(pattern)
+        .map_err(|err| Error::InvalidGlob(err.to_string()))?
+        .compile_matcher();
+
+    walk_dir(path, dir, &WalkDirOptions::new())
+        .try_filter_map(|entry| {
+            let matched = matcher.is_match(&entry.relative_path).then_some(entry.path);
+
+            futures::future::ready(Ok(matched))
+        })
+        .try_collect()
+        .await
 }
 
 /// Read a file as an UTF-8 encoded string.
@@ -300,7 +502,7 @@ pub async fn read_text_file(path: &Path, dir: BaseDirectory) -> crate::Result<St
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "fs::read_text_file")
 }
 
 /// Removes a directory.
@@ -465,6 +667,138 @@ pub async fn write_text_file(path: &Path, contents: &str, dir: BaseDirectory) ->
     .await?)
 }
 
+/// Writes a UTF-8 text file atomically.
+///
+/// The contents are written to a temporary file next to `path` which is then renamed over `path`,
+/// so readers see either the old or the new contents but never a partially written file.
+///
+/// The temporary file is named `{file name}.{random number}.tmp`, so the `fs` scope must allow any file in the
+/// directory of `path`, e.g. `$APPCONFIG/*` or `$APPCONFIG/**` for a file in the app's config directory.
+/// It deliberately doesn't start with a dot, since scopes don't match hidden files unless `requireLiteralLeadingDot` is disabled.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::fs;
+///
+/// fs::write_text_file_atomic(path, contents, BaseDirectory::AppConfig).expect("could not write config");
+/// ```
+///
+/// Requires [`allowlist > fs > writeTextFile`](https://tauri.app/v1/api/js/fs) and
+/// [`allowlist > fs > renameFile`](https://tauri.app/v1/api/js/fs) to be enabled.
+pub async fn write_text_file_atomic(
+    path: &Path,
+    contents: &str,
+    dir: BaseDirectory,
+) -> crate::Result<()> {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return Err(Error::Utf8(path.to_path_buf()));
+    };
+
+    let tmp_path = path.with_file_name(format!(
+        "{}.{}.tmp",
+        file_name,
+        (js_sys::Math::random() * u32::MAX as f64) as u32
+    ));
+
+    write_text_file(&tmp_path, contents, dir.clone()).await?;
+
+    if let Err(err) = rename_file(&tmp_path, path, dir.clone()).await {
+        if let Err(err) = remove_file(&tmp_path, dir).await {
+            log::warn!(
+                "Failed to remove temporary file {}: {}",
+                tmp_path.display(),
+                err
+            );
+        }
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Reads a file and parses its contents as JSON.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::fs;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     theme: String,
+/// }
+///
+/// let config: Config = fs::read_json(Path::new("config.json"), BaseDirectory::AppConfig).await?;
+/// ```
+///
+/// Requires [`allowlist > fs > readTextFile`](https://tauri.app/v1/api/js/fs) to be enabled.
+pub async fn read_json<T: DeserializeOwned>(path: &Path, dir: BaseDirectory) -> crate::Result<T> {
+    let contents = read_text_file(path, dir).await?;
+    let raw = js_sys::JSON::parse(&contents)
+        .map_err(|err| Error::Serde(format!("{}: {:?}", path.display(), err)))?;
+
+    crate::from_js(raw, "fs::read_json")
+}
+
+/// Serializes `value` as pretty-printed JSON and writes it to a file [atomically](write_text_file_atomic).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::fs;
+///
+/// fs::write_json(Path::new("config.json"), &config, BaseDirectory::AppConfig).await?;
+/// ```
+///
+/// Requires [`allowlist > fs > writeTextFile`](https://tauri.app/v1/api/js/fs) and
+/// [`allowlist > fs > renameFile`](https://tauri.app/v1/api/js/fs) to be enabled.
+pub async fn write_json<T: Serialize>(
+    path: &Path,
+    value: &T,
+    dir: BaseDirectory,
+) -> crate::Result<()> {
+    let raw = value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?;
+    let contents = js_sys::JSON::stringify_with_replacer_and_space(
+        &raw,
+        &wasm_bindgen::JsValue::NULL,
+        &wasm_bindgen::JsValue::from(2),
+    )
+    .map_err(|err| Error::Serde(format!("{:?}", err)))?;
+
+    write_text_file_atomic(path, &String::from(contents), dir).await
+}
+
+/// Reads a file and parses its contents as TOML.
+///
+/// Requires the `toml` feature.
+///
+/// Requires [`allowlist > fs > readTextFile`](https://tauri.app/v1/api/js/fs) to be enabled.
+#[cfg(feature = "toml")]
+pub async fn read_toml<T: DeserializeOwned>(path: &Path, dir: BaseDirectory) -> crate::Result<T> {
+    let contents = read_text_file(path, dir).await?;
+
+    toml::from_str(&contents).map_err(|err| Error::Serde(format!("{}: {}", path.display(), err)))
+}
+
+/// Serializes `value` as TOML and writes it to a file [atomically](write_text_file_atomic).
+///
+/// Requires the `toml` feature.
+///
+/// Requires [`allowlist > fs > writeTextFile`](https://tauri.app/v1/api/js/fs) and
+/// [`allowlist > fs > renameFile`](https://tauri.app/v1/api/js/fs) to be enabled.
+#[cfg(feature = "toml")]
+pub async fn write_toml<T: Serialize>(
+    path: &Path,
+    value: &T,
+    dir: BaseDirectory,
+) -> crate::Result<()> {
+    let contents = toml::to_string_pretty(value).map_err(|err| Error::Serde(err.to_string()))?;
+
+    write_text_file_atomic(path, &contents, dir).await
+}
+
 mod inner {
     use super::ArrayBuffer;
     use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
@@ -4,18 +4,18 @@
 //!
 //! ## `registerAll`
 //!
-//! ```rust,no_run
-//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-//! let shortcuts = ["CommandOrControl+Shift+C", "Ctrl+Alt+F12"];
-//!
-//! let streams = futures::future::try_join_all(shortcuts.map(|s| async move {
-//!     let stream = global_shortcut::register(s).await?;
+//! Use a [`ShortcutSet`] to register several shortcuts at once and receive their events as a single stream:
 //!
-//!     anyhow::Ok(stream.map(move |_| s))
-//! }))
-//! .await?;
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use tauri_sys::global_shortcut::ShortcutSet;
 //!
-//! let mut events = futures::stream::select_all(streams);
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut events = ShortcutSet::builder()
+//!     .add("CommandOrControl+Shift+C")
+//!     .add("Ctrl+Alt+F12")
+//!     .register()
+//!     .await?;
 //!
 //! while let Some(shortcut) = events.next().await {
 //!     log::debug!("Shortcut {} triggered", shortcut)
@@ -39,7 +39,8 @@
 //! ```
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
-use futures::{channel::mpsc, Stream, StreamExt};
+use futures::{channel::mpsc, stream::LocalBoxStream, Stream, StreamExt};
+use std::{collections::BTreeSet, fmt::Display, str::FromStr};
 use wasm_bindgen::{prelude::Closure, JsValue};
 
 /// Determines whether the given shortcut is registered by this application or not.
@@ -57,7 +58,7 @@ use wasm_bindgen::{prelude::Closure, JsValue};
 pub async fn is_registered(shortcut: &str) -> crate::Result<bool> {
     let raw = inner::isRegistered(shortcut).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "global_shortcut::is_registered")
 }
 
 /// Register a global shortcut.
@@ -118,6 +119,310 @@ impl<T> Stream for Listen<T> {
     }
 }
 
+/// Why a shortcut passed to [`register_or_report`] could not be registered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShortcutConflict {
+    /// The shortcut is already registered by this application.
+    AlreadyRegistered,
+    /// Registering failed, typically because another application holds the key combination. Contains the error reported by the backend.
+    Unavailable(String),
+    /// The backend rejected the shortcut and it is not a valid [`Accelerator`]. Contains the error reported by the backend.
+    InvalidAccelerator(String),
+}
+
+/// Registers multiple shortcuts, reporting the outcome for each one separately.
+///
+/// Unlike [`register`] a failing shortcut doesn't fail the whole call, which lets settings UIs show precise feedback per shortcut.
+/// The results are returned in the order of `shortcuts`, shortcuts that are given more than once,
+/// also in different spellings like `Ctrl+K` and `Control+K`, are only registered and reported once.
+///
+/// Fails if checking whether a shortcut is already registered fails, e.g. because the API is not allowlisted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::global_shortcut::{register_or_report, ShortcutConflict};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// for (shortcut, result) in register_or_report(["CommandOrControl+K", "Ctrl+Nope"]).await? {
+///     match result {
+///         Ok(_events) => log::info!("{} registered", shortcut),
+///         Err(ShortcutConflict::AlreadyRegistered) => log::info!("{} is already in use by this app", shortcut),
+///         Err(ShortcutConflict::Unavailable(err)) => log::warn!("{} is taken: {}", shortcut, err),
+///         Err(ShortcutConflict::InvalidAccelerator(err)) => log::warn!("{}", err),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn register_or_report<'a>(
+    shortcuts: impl IntoIterator<Item = &'a str>,
+) -> crate::Result<
+    Vec<(
+        String,
+        Result<LocalBoxStream<'static, ()>, ShortcutConflict>,
+    )>,
+> {
+    let mut seen = BTreeSet::new();
+    let shortcuts: Vec<_> = shortcuts
+        .into_iter()
+        .filter(|shortcut| {
+            let normalized = shortcut
+                .parse::<Accelerator>()
+                .map(|accelerator| accelerator.to_string())
+                .unwrap_or_else(|_| shortcut.to_string());
+
+            seen.insert(normalized)
+        })
+        .collect();
+
+    let registrations = shortcuts.into_iter().map(|shortcut| async move {
+        if is_registered(shortcut).await? {
+            return Ok((
+                shortcut.to_string(),
+                Err(ShortcutConflict::AlreadyRegistered),
+            ));
+        }
+
+        // the backend decides what is valid, the parser only tells malformed shortcuts apart from taken ones
+        let result = match register(shortcut).await {
+            Ok(events) => Ok(events.boxed_local()),
+            Err(err) => Err(match shortcut.parse::<Accelerator>() {
+                Ok(_) => ShortcutConflict::Unavailable(err.to_string()),
+                Err(_) => ShortcutConflict::InvalidAccelerator(err.to_string()),
+            }),
+        };
+
+        Ok((shortcut.to_string(), result))
+    });
+
+    futures::future::try_join_all(registrations).await
+}
+
+/// A builder for a [`ShortcutSet`].
+#[derive(Debug, Default, Clone)]
+pub struct ShortcutSetBuilder {
+    shortcuts: Vec<String>,
+}
+
+impl ShortcutSetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a shortcut to the set.
+    pub fn add(&mut self, shortcut: impl Into<String>) -> &mut Self {
+        self.shortcuts.push(shortcut.into());
+        self
+    }
+
+    /// Registers all shortcuts of the set.
+    ///
+    /// Registration is atomic: if any shortcut fails to register, the ones registered so far are unregistered again and the error is returned.
+    pub async fn register(&self) -> crate::Result<ShortcutSet> {
+        let (tx, rx) = mpsc::unbounded();
+
+        let mut set = ShortcutSet {
+            shortcuts: self.shortcuts.clone(),
+            tx,
+            rx,
+            registered: Vec::with_capacity(self.shortcuts.len()),
+        };
+        set.enable().await?;
+
+        Ok(set)
+    }
+}
+
+/// A set of global shortcuts that are registered and unregistered together.
+///
+/// The set is a [`Stream`] yielding the shortcut that was triggered.
+/// All shortcuts are unregistered when the set is dropped, or temporarily via [`ShortcutSet::disable`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::global_shortcut::ShortcutSet;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut shortcuts = ShortcutSet::builder()
+///     .add("CommandOrControl+Shift+C")
+///     .add("Ctrl+Alt+F12")
+///     .register()
+///     .await?;
+///
+/// // while a modal is open
+/// shortcuts.disable();
+/// // ...and once it is closed again
+/// shortcuts.enable().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ShortcutSet {
+    shortcuts: Vec<String>,
+    tx: mpsc::UnboundedSender<String>,
+    rx: mpsc::UnboundedReceiver<String>,
+    registered: Vec<Registration>,
+}
+
+struct Registration {
+    shortcut: String,
+    _handler: Closure<dyn FnMut(JsValue)>,
+}
+
+impl ShortcutSet {
+    pub fn builder() -> ShortcutSetBuilder {
+        ShortcutSetBuilder::new()
+    }
+
+    /// The shortcuts in this set.
+    pub fn shortcuts(&self) -> &[String] {
+        &self.shortcuts
+    }
+
+    /// Whether the shortcuts of this set are currently registered.
+    pub fn is_enabled(&self) -> bool {
+        self.registered.len() == self.shortcuts.len() && !self.shortcuts.is_empty()
+    }
+
+    /// Registers all shortcuts of this set again after they were [disabled](ShortcutSet::disable).
+    ///
+    /// Does nothing if the set is already enabled.
+    pub async fn enable(&mut self) -> crate::Result<()> {
+        if !self.registered.is_empty() {
+            return Ok(());
+        }
+
+        for shortcut in &self.shortcuts {
+            let tx = self.tx.clone();
+            let triggered = shortcut.clone();
+            let closure = Closure::<dyn FnMut(JsValue)>::new(move |_| {
+                let _ = tx.unbounded_send(triggered.clone());
+            });
+
+            if let Err(err) = inner::register(shortcut, &closure).await {
+                self.disable();
+                return Err(err.into());
+            }
+
+            self.registered.push(Registration {
+                shortcut: shortcut.clone(),
+                _handler: closure,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Unregisters all shortcuts of this set, without closing the stream.
+    pub fn disable(&mut self) {
+        for registration in self.registered.drain(..) {
+            log::debug!("Unregistering shortcut {:?}", registration.shortcut);
+            inner::unregister(JsValue::from_str(&registration.shortcut));
+        }
+    }
+}
+
+impl Drop for ShortcutSet {
+    fn drop(&mut self) {
+        self.disable();
+    }
+}
+
+impl Stream for ShortcutSet {
+    type Item = String;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+/// Register a global shortcut that is only active while `window` is focused.
+///
+/// The shortcut is unregistered when the window loses focus and registered again once it regains it,
+/// so it doesn't steal the key combination from other applications. It is unregistered for good when the returned stream is dropped.
+///
+/// Requires the `window` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::{global_shortcut::register_for_window, window::current_window};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut events = register_for_window(&current_window(), "CommandOrControl+K").await?;
+///
+/// while events.next().await.is_some() {
+///     log::debug!("Open command palette");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "window")]
+pub async fn register_for_window(
+    window: &crate::window::WebviewWindow,
+    shortcut: &str,
+) -> crate::Result<impl Stream<Item = ()>> {
+    use futures::{channel::oneshot, FutureExt};
+    use serde::de::IgnoredAny;
+
+    // listen first, focus changes while the initial state is queried are then replayed by the loop below
+    let mut focus = window.listen::<IgnoredAny>("tauri://focus").await?.fuse();
+    let mut blur = window.listen::<IgnoredAny>("tauri://blur").await?.fuse();
+
+    let mut set = ShortcutSet::builder().add(shortcut).register().await?;
+    if !window.is_focused().await? {
+        set.disable();
+    }
+
+    let (tx, rx) = mpsc::unbounded();
+    let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+
+    wasm_bindgen_futures::spawn_local(async move {
+        loop {
+            futures::select! {
+                _ = focus.next() => {
+                    if let Err(err) = set.enable().await {
+                        log::error!("Failed to register shortcut {:?}: {}", set.shortcuts(), err);
+                    }
+                }
+                _ = blur.next() => set.disable(),
+                triggered = set.next().fuse() => {
+                    if triggered.is_some() {
+                        let _ = tx.unbounded_send(());
+                    }
+                }
+                _ = stop_rx => break,
+            }
+        }
+    });
+
+    Ok(WindowShortcut { rx, _stop: stop_tx })
+}
+
+#[cfg(feature = "window")]
+struct WindowShortcut {
+    rx: mpsc::UnboundedReceiver<()>,
+    _stop: futures::channel::oneshot::Sender<()>,
+}
+
+#[cfg(feature = "window")]
+impl Stream for WindowShortcut {
+    type Item = ();
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
 /// Register a collection of global shortcuts.
 ///
 /// # Example
@@ -173,6 +478,321 @@ impl<T> Stream for Listen<T> {
 //     }
 // }
 
+/// A modifier key of an [`Accelerator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Modifier {
+    /// `Command` on macOS, `Ctrl` everywhere else.
+    CommandOrControl,
+    Control,
+    Alt,
+    Shift,
+    /// `Command` on macOS, the Windows key everywhere else.
+    Super,
+}
+
+impl Modifier {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::CommandOrControl => "CommandOrControl",
+            Self::Control => "Control",
+            Self::Alt => "Alt",
+            Self::Shift => "Shift",
+            Self::Super => "Super",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s.to_ascii_uppercase().as_str() {
+            "COMMANDORCONTROL" | "COMMANDORCTRL" | "CMDORCTRL" | "CMDORCONTROL" => {
+                Self::CommandOrControl
+            }
+            "CONTROL" | "CTRL" => Self::Control,
+            "ALT" | "OPTION" => Self::Alt,
+            "SHIFT" => Self::Shift,
+            "SUPER" | "COMMAND" | "CMD" => Self::Super,
+            _ => return None,
+        })
+    }
+}
+
+/// The non-modifier key of an [`Accelerator`].
+///
+/// Covers the keys understood by the accelerator parser of Tauri v1, keys missing here can be passed as [`Key::Other`].
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::global_shortcut::{Accelerator, Key};
+///
+/// for shortcut in ["Ctrl+KeyA", "Alt+Digit1", "Numpad0", "Shift+Plus", "CapsLock", "MediaPlayPause", "VolumeUp"] {
+///     let accelerator: Accelerator = shortcut.parse().unwrap();
+///     assert_eq!(accelerator.to_string().parse::<Accelerator>().unwrap(), accelerator);
+/// }
+///
+/// assert_eq!("Ctrl+KeyA".parse::<Accelerator>().unwrap().to_string(), "Control+A");
+/// assert_eq!("num5".parse::<Accelerator>().unwrap().key(), &Key::Numpad(5));
+/// assert_eq!(Accelerator::new([], Key::Other("FnLock".into())).to_string(), "FnLock");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A letter key `A` to `Z`, always uppercase.
+    Letter(char),
+    /// A digit key `0` to `9`.
+    Digit(u8),
+    /// A numpad digit key `Numpad0` to `Numpad9`.
+    Numpad(u8),
+    /// A function key `F1` to `F24`.
+    Function(u8),
+    Space,
+    Enter,
+    Tab,
+    Backspace,
+    Escape,
+    Delete,
+    Insert,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+    Minus,
+    Equal,
+    Plus,
+    Comma,
+    Period,
+    Slash,
+    Backslash,
+    Semicolon,
+    Quote,
+    Backquote,
+    BracketLeft,
+    BracketRight,
+    PrintScreen,
+    CapsLock,
+    NumLock,
+    ScrollLock,
+    Pause,
+    NumpadAdd,
+    NumpadSubtract,
+    NumpadMultiply,
+    NumpadDivide,
+    NumpadDecimal,
+    NumpadEnter,
+    VolumeMute,
+    VolumeDown,
+    VolumeUp,
+    MediaPlay,
+    MediaPause,
+    MediaPlayPause,
+    MediaStop,
+    MediaNextTrack,
+    MediaPreviousTrack,
+    /// Any other key name, passed to the backend as is.
+    ///
+    /// This is never produced by parsing, it only exists to build accelerators for keys this enum doesn't know yet.
+    Other(String),
+}
+
+const NAMED_KEYS: &[(Key, &str, &[&str])] = &[
+    (Key::Space, "Space", &[]),
+    (Key::Enter, "Enter", &[]),
+    (Key::Tab, "Tab", &[]),
+    (Key::Backspace, "Backspace", &[]),
+    (Key::Escape, "Escape", &["ESC"]),
+    (Key::Delete, "Delete", &[]),
+    (Key::Insert, "Insert", &[]),
+    (Key::Home, "Home", &[]),
+    (Key::End, "End", &[]),
+    (Key::PageUp, "PageUp", &[]),
+    (Key::PageDown, "PageDown", &[]),
+    (Key::Up, "Up", &["ARROWUP"]),
+    (Key::Down, "Down", &["ARROWDOWN"]),
+    (Key::Left, "Left", &["ARROWLEFT"]),
+    (Key::Right, "Right", &["ARROWRIGHT"]),
+    (Key::Minus, "Minus", &["-"]),
+    (Key::Equal, "Equal", &["="]),
+    (Key::Plus, "Plus", &[]),
+    (Key::Comma, "Comma", &[","]),
+    (Key::Period, "Period", &["."]),
+    (Key::Slash, "Slash", &["/"]),
+    (Key::Backslash, "Backslash", &["\\"]),
+    (Key::Semicolon, "Semicolon", &[";"]),
+    (Key::Quote, "Quote", &["'"]),
+    (Key::Backquote, "Backquote", &["`"]),
+    (Key::BracketLeft, "BracketLeft", &["["]),
+    (Key::BracketRight, "BracketRight", &["]"]),
+    (Key::PrintScreen, "PrintScreen", &[]),
+    (Key::CapsLock, "CapsLock", &[]),
+    (Key::NumLock, "NumLock", &[]),
+    (Key::ScrollLock, "ScrollLock", &[]),
+    (Key::Pause, "Pause", &[]),
+    (Key::NumpadAdd, "NumpadAdd", &["NUMADD"]),
+    (
+        Key::NumpadSubtract,
+        "NumpadSubtract",
+        &["NUMSUBTRACT", "NUMSUBSTRACT", "NUMPADSUBSTRACT"],
+    ),
+    (Key::NumpadMultiply, "NumpadMultiply", &["NUMMULTIPLY"]),
+    (Key::NumpadDivide, "NumpadDivide", &["NUMDIVIDE"]),
+    (Key::NumpadDecimal, "NumpadDecimal", &["NUMDECIMAL"]),
+    (Key::NumpadEnter, "NumpadEnter", &["NUMENTER"]),
+    (Key::VolumeMute, "VolumeMute", &["AUDIOVOLUMEMUTE"]),
+    (Key::VolumeDown, "VolumeDown", &["AUDIOVOLUMEDOWN"]),
+    (Key::VolumeUp, "VolumeUp", &["AUDIOVOLUMEUP"]),
+    (Key::MediaPlay, "MediaPlay", &[]),
+    (Key::MediaPause, "MediaPause", &[]),
+    (Key::MediaPlayPause, "MediaPlayPause", &[]),
+    (Key::MediaStop, "MediaStop", &[]),
+    (Key::MediaNextTrack, "MediaNextTrack", &["MEDIATRACKNEXT"]),
+    (
+        Key::MediaPreviousTrack,
+        "MediaPreviousTrack",
+        &["MEDIATRACKPREVIOUS"],
+    ),
+];
+
+impl Key {
+    fn parse(s: &str) -> Option<Self> {
+        let upper = s.to_ascii_uppercase();
+
+        if let [c] = upper.as_bytes() {
+            match c {
+                b'A'..=b'Z' => return Some(Self::Letter(*c as char)),
+                b'0'..=b'9' => return Some(Self::Digit(c - b'0')),
+                _ => {}
+            }
+        }
+
+        if let Some(&[c @ b'A'..=b'Z']) = upper.strip_prefix("KEY").map(str::as_bytes) {
+            return Some(Self::Letter(c as char));
+        }
+        if let Some(n) = upper.strip_prefix("DIGIT").and_then(|n| n.parse().ok()) {
+            return (n <= 9).then_some(Self::Digit(n));
+        }
+        if let Some(n) = upper
+            .strip_prefix("NUMPAD")
+            .or_else(|| upper.strip_prefix("NUM"))
+            .and_then(|n| n.parse().ok())
+        {
+            return (n <= 9).then_some(Self::Numpad(n));
+        }
+        if let Some(n) = upper.strip_prefix('F').and_then(|n| n.parse().ok()) {
+            return (1..=24).contains(&n).then_some(Self::Function(n));
+        }
+
+        NAMED_KEYS.iter().find_map(|(key, name, aliases)| {
+            (name.eq_ignore_ascii_case(&upper) || aliases.contains(&upper.as_str()))
+                .then(|| key.clone())
+        })
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Letter(c) => write!(f, "{}", c),
+            Self::Digit(n) => write!(f, "{}", n),
+            Self::Numpad(n) => write!(f, "Numpad{}", n),
+            Self::Function(n) => write!(f, "F{}", n),
+            Self::Other(name) => f.write_str(name),
+            key => {
+                let (_, name, _) = NAMED_KEYS.iter().find(|(k, _, _)| k == key).unwrap();
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// A validated keyboard shortcut like `CommandOrControl+Shift+C`.
+///
+/// Malformed shortcut strings are silently ignored by the backend, parsing them into an `Accelerator` first surfaces mistakes early.
+/// Use [`Accelerator::register`] to register it as a global shortcut.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::global_shortcut::{Accelerator, Key, Modifier};
+///
+/// let accelerator: Accelerator = "ctrl+shift+c".parse().unwrap();
+/// assert_eq!(accelerator.to_string(), "Control+Shift+C");
+/// assert_eq!(accelerator.key(), &Key::Letter('C'));
+///
+/// assert!("Ctrl+Shift".parse::<Accelerator>().is_err());
+/// assert_eq!(
+///     Accelerator::new([Modifier::CommandOrControl], Key::Function(5)).to_string(),
+///     "CommandOrControl+F5"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Accelerator {
+    modifiers: BTreeSet<Modifier>,
+    key: Key,
+}
+
+impl Accelerator {
+    pub fn new(modifiers: impl IntoIterator<Item = Modifier>, key: Key) -> Self {
+        Self {
+            modifiers: modifiers.into_iter().collect(),
+            key,
+        }
+    }
+
+    /// The modifiers of this accelerator, in a fixed order.
+    pub fn modifiers(&self) -> impl Iterator<Item = Modifier> + '_ {
+        self.modifiers.iter().copied()
+    }
+
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Registers this accelerator as a global shortcut, see [`register`].
+    pub async fn register(&self) -> crate::Result<impl Stream<Item = ()>> {
+        register(&self.to_string()).await
+    }
+
+    /// Determines whether this accelerator is registered by this application or not, see [`is_registered`].
+    pub async fn is_registered(&self) -> crate::Result<bool> {
+        is_registered(&self.to_string()).await
+    }
+}
+
+impl Display for Accelerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{}+", modifier.as_str())?;
+        }
+
+        write!(f, "{}", self.key)
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || crate::Error::InvalidAccelerator(s.to_string());
+
+        let mut parts: Vec<&str> = s.split('+').map(str::trim).collect();
+        let key = parts.pop().and_then(Key::parse).ok_or_else(invalid)?;
+        let modifiers = parts
+            .into_iter()
+            .map(|part| Modifier::parse(part).ok_or_else(invalid))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { modifiers, key })
+    }
+}
+
+impl From<Accelerator> for String {
+    fn from(accelerator: Accelerator) -> Self {
+        accelerator.to_string()
+    }
+}
+
 mod inner {
     // use js_sys::Array;
     use wasm_bindgen::{
@@ -120,6 +120,8 @@
 
 #[cfg(feature = "app")]
 pub mod app;
+#[cfg(feature = "appdirs")]
+pub mod appdirs;
 #[cfg(feature = "clipboard")]
 pub mod clipboard;
 #[cfg(feature = "dialog")]
@@ -137,10 +139,14 @@ pub mod mocks;
 pub mod notification;
 #[cfg(feature = "os")]
 pub mod os;
+#[cfg(feature = "panic_guard")]
+pub mod panic_guard;
 #[cfg(feature = "path")]
 pub mod path;
 #[cfg(feature = "process")]
 pub mod process;
+#[cfg(feature = "shell")]
+pub mod shell;
 #[cfg(feature = "tauri")]
 pub mod tauri;
 #[cfg(feature = "updater")]
@@ -151,19 +157,100 @@ pub mod window;
 pub use error::Error;
 pub(crate) type Result<T> = core::result::Result<T, Error>;
 
-#[cfg(any(feature = "dialog", feature = "window"))]
+/// Deserializes a value returned by the JS API, `context` names the command, event or function it came from.
+///
+/// The error message includes `context` and, in debug builds, a truncated `JSON.stringify` of the value.
+#[cfg(any(
+    feature = "app",
+    feature = "clipboard",
+    feature = "dialog",
+    feature = "event",
+    feature = "fs",
+    feature = "global_shortcut",
+    feature = "notification",
+    feature = "os",
+    feature = "path",
+    feature = "shell",
+    feature = "tauri",
+    feature = "updater",
+    feature = "window"
+))]
+pub(crate) fn from_js<T: serde::de::DeserializeOwned>(
+    raw: wasm_bindgen::JsValue,
+    context: &str,
+) -> Result<T> {
+    // cloning only copies the handle, the payload is kept for the error message
+    serde_wasm_bindgen::from_value(raw.clone()).map_err(|err| {
+        let message = format!("{}: {}", context, err);
+        #[cfg(debug_assertions)]
+        let message = {
+            const PAYLOAD_SNIPPET_LEN: usize = 200;
+
+            // `JSON.stringify` throws on cycles and BigInts and returns `undefined` for functions
+            let json = js_sys::JSON::stringify(&raw)
+                .ok()
+                .and_then(|json| json.as_string())
+                .unwrap_or_else(|| format!("{:?}", raw));
+
+            let snippet = match json.char_indices().nth(PAYLOAD_SNIPPET_LEN) {
+                Some((end, _)) => format!("{}…", &json[..end]),
+                None => json,
+            };
+
+            format!("{}, payload: {}", message, snippet)
+        };
+
+        Error::Serde(message)
+    })
+}
+
+/// Deserializes the argument of a closure created by this crate, `context` names the event or callback for error reports.
+///
+/// Panics if the argument doesn't match `T`, unless the `panic_guard` feature is enabled, see [`panic_guard`](crate::panic_guard).
+#[cfg(any(
+    feature = "event",
+    feature = "shell",
+    feature = "tauri",
+    feature = "updater",
+    feature = "window"
+))]
+pub(crate) fn closure_arg<T: serde::de::DeserializeOwned>(
+    raw: wasm_bindgen::JsValue,
+    context: &str,
+) -> Option<T> {
+    match from_js(raw, context) {
+        Ok(value) => Some(value),
+        #[cfg(feature = "panic_guard")]
+        Err(err) => {
+            panic_guard::report(context, err);
+            None
+        }
+        #[cfg(not(feature = "panic_guard"))]
+        Err(err) => panic!("Malformed payload: {}", err),
+    }
+}
+
+#[cfg(any(
+    feature = "clipboard",
+    feature = "dialog",
+    feature = "event",
+    feature = "window"
+))]
 pub(crate) mod utils {
+    #[cfg(any(feature = "dialog", feature = "window"))]
     pub struct ArrayIterator {
         pos: u32,
         arr: js_sys::Array,
     }
 
+    #[cfg(any(feature = "dialog", feature = "window"))]
     impl ArrayIterator {
         pub fn new(arr: js_sys::Array) -> Self {
             Self { pos: 0, arr }
         }
     }
 
+    #[cfg(any(feature = "dialog", feature = "window"))]
     impl Iterator for ArrayIterator {
         type Item = wasm_bindgen::JsValue;
 
@@ -179,4 +266,23 @@ pub(crate) mod utils {
             }
         }
     }
+
+    /// Resolves after `duration` has passed, using the JS `setTimeout` function.
+    #[cfg(any(feature = "clipboard", feature = "event"))]
+    pub async fn sleep(duration: std::time::Duration) {
+        use wasm_bindgen::JsCast;
+
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let global = js_sys::global();
+            let set_timeout: js_sys::Function = js_sys::Reflect::get(&global, &"setTimeout".into())
+                .unwrap()
+                .unchecked_into();
+
+            set_timeout
+                .call2(&global, &resolve, &(duration.as_millis() as f64).into())
+                .unwrap();
+        });
+
+        let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+    }
 }
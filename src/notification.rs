@@ -16,6 +16,7 @@
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
 
 /// Checks if the permission to send notifications is granted.
 ///
@@ -33,7 +34,7 @@ use serde::{Deserialize, Serialize};
 pub async fn is_permission_granted() -> crate::Result<bool> {
     let raw = inner::isPermissionGranted().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "notification::is_permission_granted")
 }
 
 /// Requests the permission to send notifications.
@@ -52,7 +53,45 @@ pub async fn is_permission_granted() -> crate::Result<bool> {
 pub async fn request_permission() -> crate::Result<Permission> {
     let raw = inner::requestPermission().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "notification::request_permission")
+}
+
+/// Makes sure the permission to send notifications is granted, prompting the user only if necessary.
+///
+/// The permission is checked first and only requested if it isn't granted yet.
+/// A [`Permission::Denied`] answer is remembered for the rest of the session, so the user isn't prompted again and again.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::notification::{self, Permission};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// if notification::ensure_permission().await? == Permission::Granted {
+///     // send notifications
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn ensure_permission() -> crate::Result<Permission> {
+    if DENIED.with(Cell::get) {
+        return Ok(Permission::Denied);
+    }
+
+    if is_permission_granted().await? {
+        return Ok(Permission::Granted);
+    }
+
+    let permission = request_permission().await?;
+    if permission == Permission::Denied {
+        DENIED.with(|denied| denied.set(true));
+    }
+
+    Ok(permission)
+}
+
+thread_local! {
+    static DENIED: Cell<bool> = const { Cell::new(false) };
 }
 
 /// Possible permission values.
@@ -118,6 +157,37 @@ impl<'a> Notification<'a> {
 
         Ok(())
     }
+
+    /// Shows the notification, requesting the permission to send notifications first if it hasn't been granted yet.
+    ///
+    /// See [`ensure_permission`] for how the permission is requested.
+    ///
+    /// Returns the resulting permission, the notification is only shown if it is [`Permission::Granted`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::notification::{Notification, Permission};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut notification = Notification::new();
+    /// notification.set_title("Tauri");
+    ///
+    /// if notification.show_with_permission_request().await? != Permission::Granted {
+    ///     log::warn!("Notifications are disabled");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn show_with_permission_request(&self) -> crate::Result<Permission> {
+        let permission = ensure_permission().await?;
+
+        if permission == Permission::Granted {
+            self.show()?;
+        }
+
+        Ok(permission)
+    }
 }
 
 mod inner {
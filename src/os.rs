@@ -15,7 +15,7 @@
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{cell::OnceCell, path::PathBuf};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum Arch {
@@ -67,6 +67,32 @@ pub enum Platform {
     Win32,
 }
 
+/// Whether a [`Platform`] is a desktop or a mobile one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlatformFamily {
+    Desktop,
+    Mobile,
+}
+
+impl Platform {
+    /// Returns whether this is a desktop or a mobile platform.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_sys::os::{Platform, PlatformFamily};
+    ///
+    /// assert_eq!(Platform::Android.family(), PlatformFamily::Mobile);
+    /// assert_eq!(Platform::Darwin.family(), PlatformFamily::Desktop);
+    /// ```
+    pub fn family(&self) -> PlatformFamily {
+        match self {
+            Self::Ios | Self::Android => PlatformFamily::Mobile,
+            _ => PlatformFamily::Desktop,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum OsKind {
     #[serde(rename = "Linux")]
@@ -82,7 +108,7 @@ pub enum OsKind {
 pub async fn arch() -> crate::Result<Arch> {
     let raw = inner::arch().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "os::arch")
 }
 
 /// Returns a string identifying the operating system platform. The value is set at compile time.
@@ -90,7 +116,7 @@ pub async fn arch() -> crate::Result<Arch> {
 pub async fn platform() -> crate::Result<Platform> {
     let raw = inner::platform().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "os::platform")
 }
 
 /// Returns the operating system's default directory for temporary files.
@@ -98,7 +124,7 @@ pub async fn platform() -> crate::Result<Platform> {
 pub async fn tempdir() -> crate::Result<PathBuf> {
     let raw = inner::tempdir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "os::tempdir")
 }
 
 /// Returns [`OsKind::Linux`] on Linux, [`OsKind::Darwin`] on macOS, and [`OsKind::WindowsNT`] on Windows.
@@ -106,7 +132,7 @@ pub async fn tempdir() -> crate::Result<PathBuf> {
 pub async fn kind() -> crate::Result<OsKind> {
     let raw = inner::kind().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "os::kind")
 }
 
 /// Returns a string identifying the kernel version.
@@ -114,7 +140,167 @@ pub async fn kind() -> crate::Result<OsKind> {
 pub async fn version() -> crate::Result<String> {
     let raw = inner::version().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "os::version")
+}
+
+/// Returns the kernel version, parsed as semver where possible.
+///
+/// Unlike [`version`] this never fails on unusual version strings, see [`OsVersion::parse`].
+pub async fn parsed_version() -> crate::Result<OsVersion> {
+    Ok(OsVersion::parse(&version().await?))
+}
+
+/// A kernel version, as returned by [`parsed_version`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum OsVersion {
+    /// The version could be read as semver.
+    Semver(semver::Version),
+    /// The version as reported by the OS, if it isn't semver-like.
+    Raw(String),
+}
+
+impl OsVersion {
+    /// Parses a version string, filling in missing minor and patch components with `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_sys::os::OsVersion;
+    ///
+    /// assert!(matches!(OsVersion::parse("6.1.0-13-amd64"), OsVersion::Semver(_)));
+    /// assert_eq!(OsVersion::parse("13.4"), OsVersion::Semver(semver::Version::new(13, 4, 0)));
+    /// assert_eq!(OsVersion::parse("unknown"), OsVersion::Raw("unknown".to_string()));
+    /// ```
+    pub fn parse(version: &str) -> Self {
+        let version = version.trim();
+
+        if let Ok(parsed) = semver::Version::parse(version) {
+            return Self::Semver(parsed);
+        }
+
+        let (core, rest) = match version.find(|c: char| !c.is_ascii_digit() && c != '.') {
+            Some(idx) => version.split_at(idx),
+            None => (version, ""),
+        };
+        let mut parts = core.split('.').map(str::parse::<u64>);
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(Ok(major)), minor, patch, None)
+                if !matches!(minor, Some(Err(_))) && !matches!(patch, Some(Err(_))) =>
+            {
+                let mut parsed = semver::Version::new(
+                    major,
+                    minor.and_then(Result::ok).unwrap_or(0),
+                    patch.and_then(Result::ok).unwrap_or(0),
+                );
+
+                if let Some(pre) = rest.strip_prefix('-') {
+                    parsed.pre = semver::Prerelease::new(pre).unwrap_or_default();
+                }
+
+                Self::Semver(parsed)
+            }
+            _ => Self::Raw(version.to_string()),
+        }
+    }
+
+    /// Returns the semver version, if the version could be parsed.
+    pub fn as_semver(&self) -> Option<&semver::Version> {
+        match self {
+            Self::Semver(version) => Some(version),
+            Self::Raw(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for OsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Semver(version) => version.fmt(f),
+            Self::Raw(version) => f.write_str(version),
+        }
+    }
+}
+
+/// Returns a String with a `BCP-47` language tag inside the string.
+///
+/// If the locale couldn't be obtained, `None` is returned instead.
+#[inline(always)]
+pub async fn locale() -> crate::Result<Option<String>> {
+    let raw = inner::locale().await?;
+
+    crate::from_js(raw, "os::locale")
+}
+
+/// Information about the operating system, as returned by [`info`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OsInfo {
+    pub platform: Platform,
+    pub arch: Arch,
+    pub kind: OsKind,
+    pub version: String,
+    pub locale: Option<String>,
+}
+
+thread_local! {
+    static INFO: OnceCell<OsInfo> = const { OnceCell::new() };
+}
+
+/// Returns information about the operating system, gathered by querying all values concurrently.
+///
+/// These values never change while the app is running, so the result is cached after the first successful call.
+/// Use [`info_uncached`] to always query the backend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::os;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let info = os::info().await?;
+/// log::info!("Running on {:?} {} ({:?})", info.kind, info.version, info.arch);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn info() -> crate::Result<OsInfo> {
+    if let Some(info) = INFO.with(|cell| cell.get().cloned()) {
+        return Ok(info);
+    }
+
+    let info = info_uncached().await?;
+    INFO.with(|cell| {
+        let _ = cell.set(info.clone());
+    });
+
+    Ok(info)
+}
+
+/// Returns `true` if the app runs on Android or iOS.
+///
+/// Uses the cached [`info`], so only the first call queries the backend.
+pub async fn is_mobile() -> crate::Result<bool> {
+    Ok(info().await?.platform.family() == PlatformFamily::Mobile)
+}
+
+/// Returns `true` if the app runs on a desktop operating system.
+///
+/// Uses the cached [`info`], so only the first call queries the backend.
+pub async fn is_desktop() -> crate::Result<bool> {
+    Ok(info().await?.platform.family() == PlatformFamily::Desktop)
+}
+
+/// Like [`info`], but always queries the backend instead of using the cached value.
+pub async fn info_uncached() -> crate::Result<OsInfo> {
+    let (platform, arch, kind, version, locale) =
+        futures::try_join!(platform(), arch(), kind(), version(), locale())?;
+
+    Ok(OsInfo {
+        platform,
+        arch,
+        kind,
+        version,
+        locale,
+    })
 }
 
 mod inner {
@@ -132,5 +318,7 @@ mod inner {
         pub async fn kind() -> Result<JsValue, JsValue>;
         #[wasm_bindgen(catch)]
         pub async fn version() -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(catch)]
+        pub async fn locale() -> Result<JsValue, JsValue>;
     }
 }
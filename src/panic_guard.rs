@@ -0,0 +1,55 @@
+//! Keeps malformed payloads from taking down the whole app.
+//!
+//! Closures created by this crate, e.g. for event listeners, deserialize their payload before handing it to your code.
+//! A payload that doesn't match the expected type normally panics, which aborts the WASM instance.
+//! With the `panic_guard` feature enabled the payload is dropped instead, the error is logged and,
+//! if the `event` feature is enabled, emitted as [`ERROR_EVENT`].
+//!
+//! Panics in your own code can't be caught on `wasm32-unknown-unknown`, call [`install`] to at least have them logged to the console.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use futures::StreamExt;
+//! use tauri_sys::{event::listen, panic_guard};
+//!
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! panic_guard::install();
+//!
+//! let mut errors = listen::<String>(panic_guard::ERROR_EVENT).await?;
+//! while let Some(error) = errors.next().await {
+//!     log::warn!("Dropped a malformed payload: {}", error.payload);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+/// The event malformed payloads are reported as, the payload is the error message.
+pub const ERROR_EVENT: &str = "tauri-sys://error";
+
+/// Installs a panic hook that logs panics to the browser console.
+pub fn install() {
+    console_error_panic_hook::set_once();
+}
+
+#[cfg(any(
+    feature = "event",
+    feature = "shell",
+    feature = "tauri",
+    feature = "updater",
+    feature = "window"
+))]
+pub(crate) fn report(context: &str, err: crate::Error) {
+    let message = format!("Malformed payload: {}", err);
+    log::error!("{}", message);
+
+    // a listener for the error event with the wrong type would otherwise report itself forever
+    #[cfg(feature = "event")]
+    if context != ERROR_EVENT {
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = crate::event::emit(ERROR_EVENT, &message).await;
+        });
+    }
+    #[cfg(not(feature = "event"))]
+    let _ = context;
+}
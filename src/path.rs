@@ -35,7 +35,7 @@ use wasm_bindgen::JsValue;
 pub async fn app_config_dir() -> crate::Result<PathBuf> {
     let raw = inner::appConfigDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::app_config_dir")
 }
 
 /// Returns the path to the suggested directory for your app's data files.
@@ -56,7 +56,7 @@ pub async fn app_config_dir() -> crate::Result<PathBuf> {
 pub async fn app_data_dir() -> crate::Result<PathBuf> {
     let raw = inner::appDataDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::app_data_dir")
 }
 
 /// Returns the path to the suggested directory for your app's local data files.
@@ -77,7 +77,7 @@ pub async fn app_data_dir() -> crate::Result<PathBuf> {
 pub async fn app_local_data_dir() -> crate::Result<PathBuf> {
     let raw = inner::appLocalDataDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::app_local_data_dir")
 }
 
 /// Returns the path to the suggested directory for your app's cache files.
@@ -98,7 +98,7 @@ pub async fn app_local_data_dir() -> crate::Result<PathBuf> {
 pub async fn app_cache_dir() -> crate::Result<PathBuf> {
     let raw = inner::appCacheDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::app_cache_dir")
 }
 
 /// Returns the path to the user's audio directory.
@@ -123,7 +123,7 @@ pub async fn app_cache_dir() -> crate::Result<PathBuf> {
 pub async fn audio_dir() -> crate::Result<PathBuf> {
     let raw = inner::audioDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::audio_dir")
 }
 
 /// Returns the path to the user's cache directory.
@@ -148,7 +148,7 @@ pub async fn audio_dir() -> crate::Result<PathBuf> {
 pub async fn cache_dir() -> crate::Result<PathBuf> {
     let raw = inner::cacheDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::cache_dir")
 }
 
 /// Returns the path to the user's config directory.
@@ -173,7 +173,7 @@ pub async fn cache_dir() -> crate::Result<PathBuf> {
 pub async fn config_dir() -> crate::Result<PathBuf> {
     let raw = inner::configDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::config_dir")
 }
 
 /// Returns the path to the user's data directory.
@@ -198,7 +198,7 @@ pub async fn config_dir() -> crate::Result<PathBuf> {
 pub async fn data_dir() -> crate::Result<PathBuf> {
     let raw = inner::dataDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::data_dir")
 }
 
 /// Returns the path to the user's desktop directory.
@@ -223,7 +223,7 @@ pub async fn data_dir() -> crate::Result<PathBuf> {
 pub async fn desktop_dir() -> crate::Result<PathBuf> {
     let raw = inner::desktopDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::desktop_dir")
 }
 
 /// Returns the path to the user's document directory.
@@ -248,7 +248,7 @@ pub async fn desktop_dir() -> crate::Result<PathBuf> {
 pub async fn document_dir() -> crate::Result<PathBuf> {
     let raw = inner::documentDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::document_dir")
 }
 
 /// Returns the path to the user's download directory.
@@ -273,7 +273,7 @@ pub async fn document_dir() -> crate::Result<PathBuf> {
 pub async fn download_dir() -> crate::Result<PathBuf> {
     let raw = inner::downloadDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::download_dir")
 }
 
 /// Returns the path to the user's executable directory.
@@ -298,7 +298,7 @@ pub async fn download_dir() -> crate::Result<PathBuf> {
 pub async fn executable_dir() -> crate::Result<PathBuf> {
     let raw = inner::executableDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::executable_dir")
 }
 
 /// Returns the path to the user's font directory.
@@ -323,7 +323,7 @@ pub async fn executable_dir() -> crate::Result<PathBuf> {
 pub async fn font_dir() -> crate::Result<PathBuf> {
     let raw = inner::fontDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::font_dir")
 }
 
 /// Returns the path to the user's home directory.
@@ -348,7 +348,7 @@ pub async fn font_dir() -> crate::Result<PathBuf> {
 pub async fn home_dir() -> crate::Result<PathBuf> {
     let raw = inner::homeDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::home_dir")
 }
 
 /// Returns the path to the user's local data directory.
@@ -373,7 +373,7 @@ pub async fn home_dir() -> crate::Result<PathBuf> {
 pub async fn local_data_dir() -> crate::Result<PathBuf> {
     let raw = inner::localDataDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::local_data_dir")
 }
 
 /// Returns the path to the user's picture directory.
@@ -398,7 +398,7 @@ pub async fn local_data_dir() -> crate::Result<PathBuf> {
 pub async fn picture_dir() -> crate::Result<PathBuf> {
     let raw = inner::pictureDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::picture_dir")
 }
 
 /// Returns the path to the user's public directory.
@@ -423,7 +423,7 @@ pub async fn picture_dir() -> crate::Result<PathBuf> {
 pub async fn public_dir() -> crate::Result<PathBuf> {
     let raw = inner::publicDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::public_dir")
 }
 
 /// Returns the path to the application's resource directory.
@@ -444,7 +444,7 @@ pub async fn public_dir() -> crate::Result<PathBuf> {
 pub async fn resource_dir() -> crate::Result<PathBuf> {
     let raw = inner::resourceDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::resource_dir")
 }
 
 /// Resolve the path to a resource file.
@@ -465,7 +465,7 @@ pub async fn resource_dir() -> crate::Result<PathBuf> {
 pub async fn resolve_resource(resource_path: &str) -> crate::Result<PathBuf> {
     let raw = inner::resolveResource(JsValue::from_str(resource_path)).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::resolve_resource")
 }
 
 /// Returns the path to the user's runtime directory.
@@ -490,7 +490,7 @@ pub async fn resolve_resource(resource_path: &str) -> crate::Result<PathBuf> {
 pub async fn runtime_dir() -> crate::Result<PathBuf> {
     let raw = inner::runtimeDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::runtime_dir")
 }
 
 /// Returns the path to the user's template directory.
@@ -515,7 +515,7 @@ pub async fn runtime_dir() -> crate::Result<PathBuf> {
 pub async fn template_dir() -> crate::Result<PathBuf> {
     let raw = inner::templateDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::template_dir")
 }
 
 /// Returns the path to the user's video directory.
@@ -540,7 +540,7 @@ pub async fn template_dir() -> crate::Result<PathBuf> {
 pub async fn video_dir() -> crate::Result<PathBuf> {
     let raw = inner::videoDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::video_dir")
 }
 
 /// Returns the path to the suggested directory for your app's log files.
@@ -565,7 +565,7 @@ pub async fn video_dir() -> crate::Result<PathBuf> {
 pub async fn app_log_dir() -> crate::Result<PathBuf> {
     let raw = inner::appLogDir().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::app_log_dir")
 }
 
 /// Resolves a sequence of `paths` or `path` segments into an absolute path.
@@ -587,7 +587,7 @@ pub async fn resolve(paths: impl IntoIterator<Item = &str>) -> crate::Result<Pat
     let paths = paths.into_iter();
     let raw = inner::resolve(serde_wasm_bindgen::to_value(&paths.collect::<Vec<_>>())?).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::resolve")
 }
 
 /// Normalizes the given `path`, resolving `'..'` and `'.'` segments and resolve symbolic links.
@@ -608,7 +608,7 @@ pub async fn resolve(paths: impl IntoIterator<Item = &str>) -> crate::Result<Pat
 pub async fn normalize(path: &str) -> crate::Result<PathBuf> {
     let raw = inner::normalize(JsValue::from_str(path)).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::normalize")
 }
 
 ///  Joins all given `path` segments together using the platform-specific separator as a delimiter, then normalizes the resulting path.
@@ -630,7 +630,7 @@ pub async fn join(paths: impl IntoIterator<Item = &str>) -> crate::Result<PathBu
     let paths = paths.into_iter();
     let raw = inner::join(serde_wasm_bindgen::to_value(&paths.collect::<Vec<_>>())?).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::join")
 }
 
 /// Returns the directory name of a `path`. Trailing directory separators are ignored.
@@ -651,7 +651,7 @@ pub async fn join(paths: impl IntoIterator<Item = &str>) -> crate::Result<PathBu
 pub async fn dirname(path: &str) -> crate::Result<PathBuf> {
     let raw = inner::dirname(JsValue::from_str(path)).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::dirname")
 }
 
 /// Returns the extension of the `path`.
@@ -672,7 +672,7 @@ pub async fn dirname(path: &str) -> crate::Result<PathBuf> {
 pub async fn extname(path: &str) -> crate::Result<PathBuf> {
     let raw = inner::extname(JsValue::from_str(path)).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::extname")
 }
 
 /// Returns the last portion of a `path`. Trailing directory separators are ignored.
@@ -699,7 +699,7 @@ pub async fn basename(path: &str, ext: Option<&str>) -> crate::Result<PathBuf> {
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::basename")
 }
 
 /// Returns whether the path is absolute or not.
@@ -718,7 +718,7 @@ pub async fn basename(path: &str, ext: Option<&str>) -> crate::Result<PathBuf> {
 pub async fn is_absolute(path: &str) -> crate::Result<bool> {
     let raw = inner::isAbsolute(JsValue::from_str(path)).await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "path::is_absolute")
 }
 
 mod inner {
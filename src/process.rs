@@ -13,6 +13,145 @@ pub fn relaunch() {
     inner::relaunch();
 }
 
+/// The event the backend emits to ask the frontend whether the app may exit, see [`on_exit_requested`].
+#[cfg(feature = "event")]
+pub const EXIT_REQUESTED_EVENT: &str = "tauri-sys://exit-requested";
+
+/// The event [`ExitRequest::allow`] and [`ExitRequest::deny`] answer with.
+#[cfg(feature = "event")]
+pub const EXIT_RESPONSE_EVENT: &str = "tauri-sys://exit-response";
+
+/// Listen to exit requests forwarded by the backend, e.g. to ask the user about unsaved changes before quitting.
+///
+/// Tauri doesn't tell the frontend when the app is about to exit. The backend has to intercept the
+/// `WindowEvent::CloseRequested` of the last window, keep it open with `api.prevent_close()` and emit
+/// [`EXIT_REQUESTED_EVENT`] with a unique id. The [`EXIT_RESPONSE_EVENT`] reply carries that id back,
+/// so the backend can ignore replies to requests that were superseded, and exits when the reply allows it:
+///
+/// ```rust,ignore
+/// // src-tauri/src/main.rs
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::sync::Arc;
+///
+/// static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+/// // the id of the request the frontend is expected to answer, 0 if there is none
+/// let pending = Arc::new(AtomicU32::new(0));
+///
+/// tauri::Builder::default()
+///     .setup({
+///         let pending = pending.clone();
+///         move |app| {
+///             let handle = app.handle();
+///             app.listen_global("tauri-sys://exit-response", move |event| {
+///                 let response: serde_json::Value = serde_json::from_str(event.payload().unwrap()).unwrap();
+///                 let id = response["id"].as_u64().unwrap_or(0) as u32;
+///                 if id == 0 || pending.compare_exchange(id, 0, Ordering::SeqCst, Ordering::SeqCst).is_err() {
+///                     return;
+///                 }
+///                 if response["allow"].as_bool() == Some(true) {
+///                     handle.exit(response["code"].as_i64().unwrap_or(0) as i32);
+///                 }
+///             });
+///             Ok(())
+///         }
+///     })
+///     .on_window_event(move |event| {
+///         if let tauri::WindowEvent::CloseRequested { api, .. } = event.event() {
+///             let window = event.window();
+///             if window.app_handle().windows().len() == 1 {
+///                 api.prevent_close();
+///                 let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+///                 pending.store(id, Ordering::SeqCst);
+///                 window
+///                     .emit_all("tauri-sys://exit-requested", serde_json::json!({ "id": id, "code": null }))
+///                     .unwrap();
+///             }
+///         }
+///     })
+///     .run(tauri::generate_context!())
+///     .unwrap();
+/// ```
+///
+/// Requires the `event` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::process::on_exit_requested;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut requests = on_exit_requested().await?;
+///
+/// while let Some(request) = requests.next().await {
+///     let has_unsaved_changes = true;
+///
+///     if has_unsaved_changes {
+///         request.deny().await?;
+///     } else {
+///         request.allow().await?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "event")]
+pub async fn on_exit_requested() -> crate::Result<impl futures::Stream<Item = ExitRequest>> {
+    use futures::StreamExt;
+
+    let events = crate::event::listen::<ExitRequest>(EXIT_REQUESTED_EVENT).await?;
+
+    Ok(events.map(|event| event.payload))
+}
+
+/// A request of the backend to exit the app, see [`on_exit_requested`].
+///
+/// Dropping the request without answering keeps the app running, with the backend flow
+/// shown in [`on_exit_requested`] the window stays open until it is closed again.
+#[cfg(feature = "event")]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ExitRequest {
+    id: u32,
+    code: Option<i32>,
+}
+
+#[cfg(feature = "event")]
+impl ExitRequest {
+    /// The exit code the app would exit with, `None` when the request comes from closing the last window.
+    pub fn code(&self) -> Option<i32> {
+        self.code
+    }
+
+    /// Lets the app exit.
+    pub async fn allow(self) -> crate::Result<()> {
+        self.respond(true).await
+    }
+
+    /// Keeps the app running.
+    pub async fn deny(self) -> crate::Result<()> {
+        self.respond(false).await
+    }
+
+    async fn respond(self, allow: bool) -> crate::Result<()> {
+        #[derive(serde::Serialize)]
+        struct ExitResponse {
+            id: u32,
+            code: Option<i32>,
+            allow: bool,
+        }
+
+        crate::event::emit(
+            EXIT_RESPONSE_EVENT,
+            &ExitResponse {
+                id: self.id,
+                code: self.code,
+                allow,
+            },
+        )
+        .await
+    }
+}
+
 mod inner {
     use wasm_bindgen::prelude::*;
 
@@ -0,0 +1,407 @@
+//! Access the system shell. Allows you to spawn child processes and manage files and URLs using their default application.
+//!
+//! The APIs must be added to tauri.allowlist.shell in tauri.conf.json:
+//! ```json
+//! {
+//!     "tauri": {
+//!         "allowlist": {
+//!             "shell": {
+//!                 "all": true, // enable all shell APIs
+//!                 "execute": true, // enable process spawn APIs
+//!                 "sidecar": true, // enable spawning sidecars
+//!                 "open": true // enable opening files/URLs using the default program
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+//! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
+
+use futures::{channel::mpsc, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, path::PathBuf};
+use url::Url;
+use wasm_bindgen::{prelude::Closure, JsValue};
+
+/// The URL schemes accepted by the default `shell > open` scope, which is `^((mailto:\w+)|(tel:\w+)|(https?://\w+)).+`.
+pub const DEFAULT_OPEN_SCHEMES: &[&str] = &["http", "https", "mailto", "tel"];
+
+/// Validates that `path` is a URL whose scheme is one of `allowed_schemes`.
+///
+/// Returns [`Error::InvalidPath`](crate::Error::InvalidPath) if `path` is not a valid URL
+/// and [`Error::SchemeNotAllowed`](crate::Error::SchemeNotAllowed) if its scheme isn't allowed.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::shell::{validate_open_target, DEFAULT_OPEN_SCHEMES};
+///
+/// assert!(validate_open_target("https://tauri.app", DEFAULT_OPEN_SCHEMES).is_ok());
+/// assert!(validate_open_target("file:///etc/passwd", DEFAULT_OPEN_SCHEMES).is_err());
+/// ```
+pub fn validate_open_target(path: &str, allowed_schemes: &[&str]) -> crate::Result<Url> {
+    let url = Url::parse(path).map_err(|_| crate::Error::InvalidPath(path.to_string()))?;
+
+    if allowed_schemes.contains(&url.scheme()) {
+        Ok(url)
+    } else {
+        Err(crate::Error::SchemeNotAllowed {
+            url: path.to_string(),
+            scheme: url.scheme().to_string(),
+        })
+    }
+}
+
+/// Opens a URL with the system's default app, or the one specified with `with`.
+///
+/// Only URLs with one of the [`DEFAULT_OPEN_SCHEMES`] are accepted, anything else is rejected before it reaches the backend.
+/// File paths and `file://` URLs fail with [`Error::InvalidPath`](crate::Error::InvalidPath) or
+/// [`Error::SchemeNotAllowed`](crate::Error::SchemeNotAllowed), open them with [`open_unchecked`] and a
+/// `shell > open` scope configured with a custom regex that allows them.
+///
+/// The `with` value must be one of `firefox`, `google chrome`, `chromium` `safari`,
+/// `open`, `start`, `xdg-open`, `gio`, `gnome-open`, `kde-open` or `wslview`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::shell::open;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // opens the given URL on the default browser:
+/// open("https://github.com/tauri-apps/tauri", None).await?;
+/// // opens the given URL using `firefox`:
+/// open("https://github.com/tauri-apps/tauri", Some("firefox")).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires [`allowlist > shell > open`](https://tauri.app/v1/api/config#shellallowlistconfig.open) to be enabled.
+pub async fn open(path: &str, with: Option<&str>) -> crate::Result<()> {
+    validate_open_target(path, DEFAULT_OPEN_SCHEMES)?;
+
+    open_unchecked(path, with).await
+}
+
+/// Opens a path or URL with the system's default app, or the one specified with `with`, without validating it on the frontend.
+///
+/// The backend still checks `path` against the `shell > open` scope.
+///
+/// Requires [`allowlist > shell > open`](https://tauri.app/v1/api/config#shellallowlistconfig.open) to be enabled.
+pub async fn open_unchecked(path: &str, with: Option<&str>) -> crate::Result<()> {
+    Ok(inner::open(path, with).await?)
+}
+
+/// The payload of a process termination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct TerminatedPayload {
+    /// Exit code of the process, `None` if the process was terminated by a signal on Unix.
+    pub code: Option<i32>,
+    /// If the process was terminated by a signal, represents that signal.
+    pub signal: Option<i32>,
+}
+
+/// Events emitted by a spawned child process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandEvent {
+    /// A line written to the process' stdout.
+    Stdout(String),
+    /// A line written to the process' stderr.
+    Stderr(String),
+    /// An error happened while waiting for the process or reading its output.
+    Error(String),
+    /// The process terminated.
+    Terminated(TerminatedPayload),
+}
+
+/// The output of a finished process, as returned by [`Command::output`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Output {
+    /// Exit code of the process, `None` if the process was terminated by a signal on Unix.
+    pub code: Option<i32>,
+    /// If the process was terminated by a signal, represents that signal.
+    pub signal: Option<i32>,
+    /// The data that the process wrote to stdout.
+    pub stdout: String,
+    /// The data that the process wrote to stderr.
+    pub stderr: String,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SpawnOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwd: Option<PathBuf>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    env: HashMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding: Option<String>,
+}
+
+/// The entry point for spawning child processes.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::shell::{Command, CommandEvent};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (mut events, child) = Command::sidecar("binaries/my-sidecar")
+///     .add_args(["--port", "8080"])
+///     .spawn()
+///     .await?;
+///
+/// log::info!("Spawned sidecar with pid {}", child.pid());
+///
+/// while let Some(event) = events.next().await {
+///     match event {
+///         CommandEvent::Stdout(line) => log::info!("{}", line),
+///         CommandEvent::Stderr(line) => log::warn!("{}", line),
+///         CommandEvent::Error(err) => log::error!("{}", err),
+///         CommandEvent::Terminated(payload) => log::info!("Exited with {:?}", payload.code),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Command {
+    program: String,
+    args: Vec<String>,
+    sidecar: bool,
+    options: SpawnOptions,
+}
+
+impl Command {
+    /// Creates a command to execute the given program.
+    ///
+    /// The program name must be configured on `tauri.conf.json > tauri > allowlist > shell > scope`.
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            sidecar: false,
+            options: SpawnOptions::default(),
+        }
+    }
+
+    /// Creates a command to execute the given sidecar program.
+    ///
+    /// The program must be configured on `tauri.conf.json > tauri > bundle > externalBin`.
+    pub fn sidecar(program: impl Into<String>) -> Self {
+        Self {
+            sidecar: true,
+            ..Self::new(program)
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn add_arg(&mut self, arg: impl Into<String>) -> &mut Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Adds multiple arguments to pass to the program.
+    pub fn add_args(&mut self, args: impl IntoIterator<Item = impl Into<String>>) -> &mut Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the working directory of the process.
+    pub fn set_current_dir(&mut self, current_dir: impl Into<PathBuf>) -> &mut Self {
+        self.options.cwd = Some(current_dir.into());
+        self
+    }
+
+    /// Sets an environment variable for the process.
+    pub fn set_env(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.options.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Sets the character encoding for stdout/stderr.
+    pub fn set_encoding(&mut self, encoding: impl Into<String>) -> &mut Self {
+        self.options.encoding = Some(encoding.into());
+        self
+    }
+
+    fn to_js(&self) -> crate::Result<inner::Command> {
+        let args = self.args.iter().map(|arg| JsValue::from_str(arg)).collect();
+        let options = serde_wasm_bindgen::to_value(&self.options)?;
+
+        if self.sidecar {
+            Ok(inner::Command::sidecar(&self.program, args, options))
+        } else {
+            Ok(inner::Command::new(&self.program, args, options))
+        }
+    }
+
+    /// Executes the command as a child process, waiting for it to finish and collecting all of its output.
+    ///
+    /// Requires [`allowlist > shell > execute`](https://tauri.app/v1/api/config#shellallowlistconfig.execute)
+    /// or [`allowlist > shell > sidecar`](https://tauri.app/v1/api/config#shellallowlistconfig.sidecar) to be enabled.
+    pub async fn output(&self) -> crate::Result<Output> {
+        let raw = self.to_js()?.execute().await?;
+
+        crate::from_js(raw, "shell::output")
+    }
+
+    /// Executes the command as a child process, returning a stream of its events and a handle to it.
+    ///
+    /// The stream ends after [`CommandEvent::Terminated`]. The event listeners are detached when the stream is dropped,
+    /// the process itself keeps running until it exits or is [killed](Child::kill).
+    ///
+    /// Requires [`allowlist > shell > execute`](https://tauri.app/v1/api/config#shellallowlistconfig.execute)
+    /// or [`allowlist > shell > sidecar`](https://tauri.app/v1/api/config#shellallowlistconfig.sidecar) to be enabled.
+    pub async fn spawn(&self) -> crate::Result<(impl Stream<Item = CommandEvent>, Child)> {
+        let command = self.to_js()?;
+        let (tx, rx) = mpsc::unbounded::<CommandEvent>();
+
+        let stdout_tx = tx.clone();
+        let stdout = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+            let _ =
+                stdout_tx.unbounded_send(CommandEvent::Stdout(raw.as_string().unwrap_or_default()));
+        });
+        let stderr_tx = tx.clone();
+        let stderr = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+            let _ =
+                stderr_tx.unbounded_send(CommandEvent::Stderr(raw.as_string().unwrap_or_default()));
+        });
+        let error_tx = tx.clone();
+        let error = Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+            let _ = error_tx.unbounded_send(CommandEvent::Error(
+                raw.as_string().unwrap_or_else(|| format!("{:?}", raw)),
+            ));
+        });
+        let close = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+            if let Some(payload) = crate::closure_arg(raw, "command close") {
+                let _ = tx.unbounded_send(CommandEvent::Terminated(payload));
+            }
+            // the closures own the senders, so the stream would never end otherwise
+            tx.close_channel();
+        });
+
+        command.stdout().on("data", &stdout);
+        command.stderr().on("data", &stderr);
+        command.on("error", &error);
+        command.on("close", &close);
+
+        let events = CommandEvents {
+            rx,
+            command,
+            _handlers: [stdout, stderr, error, close],
+        };
+        let child = events.command.spawn().await?;
+
+        Ok((events, Child(child)))
+    }
+}
+
+struct CommandEvents {
+    rx: mpsc::UnboundedReceiver<CommandEvent>,
+    command: inner::Command,
+    _handlers: [Closure<dyn FnMut(JsValue)>; 4],
+}
+
+impl Drop for CommandEvents {
+    fn drop(&mut self) {
+        log::debug!("Removing listeners for child process events");
+        self.command.stdout().removeAllListeners();
+        self.command.stderr().removeAllListeners();
+        self.command.removeAllListeners();
+    }
+}
+
+impl Stream for CommandEvents {
+    type Item = CommandEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+/// A handle to a spawned child process.
+#[derive(Debug, Clone)]
+pub struct Child(inner::Child);
+
+impl Child {
+    /// The child process `pid`.
+    pub fn pid(&self) -> u32 {
+        self.0.pid()
+    }
+
+    /// Writes `data` to the process' stdin.
+    pub async fn write(&self, data: &[u8]) -> crate::Result<()> {
+        let buffer: js_sys::Array = data.iter().map(|b| JsValue::from(*b)).collect();
+
+        Ok(self.0.write(buffer.into()).await?)
+    }
+
+    /// Kills the child process.
+    pub async fn kill(self) -> crate::Result<()> {
+        Ok(self.0.kill().await?)
+    }
+}
+
+mod inner {
+    use js_sys::Array;
+    use wasm_bindgen::{
+        prelude::{wasm_bindgen, Closure},
+        JsValue,
+    };
+
+    #[wasm_bindgen(module = "/src/shell.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type EventEmitter;
+        #[wasm_bindgen(method)]
+        pub fn on(
+            this: &EventEmitter,
+            eventName: &str,
+            listener: &Closure<dyn FnMut(JsValue)>,
+        ) -> EventEmitter;
+        #[wasm_bindgen(method)]
+        pub fn removeAllListeners(this: &EventEmitter) -> EventEmitter;
+    }
+
+    #[wasm_bindgen(module = "/src/shell.js")]
+    extern "C" {
+        #[wasm_bindgen(extends = EventEmitter)]
+        #[derive(Debug, Clone)]
+        pub type Command;
+        #[wasm_bindgen(constructor)]
+        pub fn new(program: &str, args: Array, options: JsValue) -> Command;
+        #[wasm_bindgen(static_method_of = Command)]
+        pub fn sidecar(program: &str, args: Array, options: JsValue) -> Command;
+        #[wasm_bindgen(method, getter)]
+        pub fn stdout(this: &Command) -> EventEmitter;
+        #[wasm_bindgen(method, getter)]
+        pub fn stderr(this: &Command) -> EventEmitter;
+        #[wasm_bindgen(method, catch)]
+        pub async fn spawn(this: &Command) -> Result<Child, JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn execute(this: &Command) -> Result<JsValue, JsValue>;
+    }
+
+    #[wasm_bindgen(module = "/src/shell.js")]
+    extern "C" {
+        #[derive(Debug, Clone)]
+        pub type Child;
+        #[wasm_bindgen(method, getter)]
+        pub fn pid(this: &Child) -> u32;
+        #[wasm_bindgen(method, catch)]
+        pub async fn write(this: &Child, data: JsValue) -> Result<(), JsValue>;
+        #[wasm_bindgen(method, catch)]
+        pub async fn kill(this: &Child) -> Result<(), JsValue>;
+    }
+
+    #[wasm_bindgen(module = "/src/shell.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn open(path: &str, openWith: Option<&str>) -> Result<(), JsValue>;
+    }
+}
@@ -1,7 +1,10 @@
 //! Invoke your custom commands.
 
+use futures::{future, Future};
 use serde::{de::DeserializeOwned, Serialize};
+use std::{cell::Cell, path::Path, time::Duration};
 use url::Url;
+use wasm_bindgen::JsCast;
 
 /// Convert a device file path to an URL that can be loaded by the webview.
 ///
@@ -42,7 +45,36 @@ use url::Url;
 pub async fn convert_file_src(file_path: &str, protocol: Option<&str>) -> crate::Result<Url> {
     let js_val = inner::convertFileSrc(file_path, protocol).await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "tauri::convert_file_src")
+}
+
+/// Convert a device file path to an URL that can be loaded by the webview.
+///
+/// Same as [`convert_file_src`] but takes a [`Path`], e.g. one returned by the `path` or `dialog` modules.
+/// Windows paths are passed through unchanged, their separators are percent-encoded and decoded again by the asset protocol handler.
+///
+/// Whether the asset protocol scope allows the path is only checked by the backend once the URL is loaded.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::tauri::convert_file_src_path;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = convert_file_src_path(Path::new(r"C:\Users\tauri\video.mp4"), None).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn convert_file_src_path(
+    file_path: impl AsRef<Path>,
+    protocol: Option<&str>,
+) -> crate::Result<Url> {
+    let file_path = file_path.as_ref();
+    let Some(path) = file_path.to_str() else {
+        return Err(crate::Error::Utf8(file_path.to_path_buf()));
+    };
+
+    convert_file_src(path, protocol).await
 }
 
 /// Sends a message to the backend.
@@ -65,9 +97,386 @@ pub async fn convert_file_src(file_path: &str, protocol: Option<&str>) -> crate:
 /// @return A promise resolving or rejecting to the backend response.
 #[inline(always)]
 pub async fn invoke<A: Serialize, R: DeserializeOwned>(cmd: &str, args: &A) -> crate::Result<R> {
-    let raw = inner::invoke(cmd, serde_wasm_bindgen::to_value(args)?).await?;
+    let args = serde_wasm_bindgen::to_value(args)?;
+
+    #[cfg(debug_assertions)]
+    warn_snake_case_keys(cmd, &args);
+
+    let raw = observed_invoke(cmd, args).await?;
+
+    crate::from_js(raw, cmd)
+}
+
+/// Sends a message to the backend, converting the top-level argument keys from `snake_case` to `camelCase`.
+///
+/// Tauri commands expect their arguments in camelCase, this saves adding `#[serde(rename_all = "camelCase")]` to every argument struct.
+/// Nested values are passed through unchanged.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::tauri::invoke_camel_case;
+///
+/// #[derive(serde::Serialize)]
+/// struct Args<'a> {
+///     file_path: &'a str,
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// // the command receives `{ filePath: "notes.md" }`
+/// let contents: String = invoke_camel_case("read_note", &Args { file_path: "notes.md" }).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn invoke_camel_case<A: Serialize, R: DeserializeOwned>(
+    cmd: &str,
+    args: &A,
+) -> crate::Result<R> {
+    let args = serde_wasm_bindgen::to_value(args)?;
+    let raw = observed_invoke(cmd, camel_case_keys(args)?).await?;
+
+    crate::from_js(raw, cmd)
+}
+
+fn is_plain_object(value: &wasm_bindgen::JsValue) -> bool {
+    value.is_object() && !js_sys::Array::is_array(value)
+}
+
+fn camel_case_keys(args: wasm_bindgen::JsValue) -> crate::Result<wasm_bindgen::JsValue> {
+    if !is_plain_object(&args) {
+        return Ok(args);
+    }
+
+    let converted = js_sys::Object::new();
+    for key in js_sys::Object::keys(args.unchecked_ref()).iter() {
+        let value = js_sys::Reflect::get(&args, &key)?;
+        let key = to_camel_case(&key.as_string().unwrap_or_default());
+
+        js_sys::Reflect::set(&converted, &key.into(), &value)?;
+    }
+
+    Ok(converted.into())
+}
+
+/// Converts a `snake_case` argument name to the `camelCase` the backend expects, see [`invoke_camel_case`].
+///
+/// Leading underscores are kept.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::tauri::to_camel_case;
+///
+/// assert_eq!(to_camel_case("file_path"), "filePath");
+/// assert_eq!(to_camel_case("max_retry_count"), "maxRetryCount");
+/// assert_eq!(to_camel_case("_private_key"), "_privateKey");
+/// assert_eq!(to_camel_case("alreadyCamel"), "alreadyCamel");
+/// ```
+pub fn to_camel_case(key: &str) -> String {
+    let trimmed = key.trim_start_matches('_');
+    let mut out = key[..key.len() - trimmed.len()].to_string();
+    let mut upper = false;
+
+    for c in trimmed.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
+
+#[cfg(debug_assertions)]
+fn warn_snake_case_keys(cmd: &str, args: &wasm_bindgen::JsValue) {
+    if !is_plain_object(args) {
+        return;
+    }
+
+    for key in js_sys::Object::keys(args.unchecked_ref()).iter() {
+        let Some(key) = key.as_string() else {
+            continue;
+        };
+
+        if key.trim_start_matches('_').contains('_') {
+            log::warn!(
+                "Argument {:?} of command {:?} is snake_case, but Tauri commands expect camelCase arguments. Use `invoke_camel_case` or `#[serde(rename_all = \"camelCase\")]`.",
+                key,
+                cmd
+            );
+        }
+    }
+}
+
+/// Sends a message to the backend with untyped JSON arguments, returning the untyped JSON response.
+///
+/// Useful for tooling that can't know the argument and response types at compile time, e.g. command explorers.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use serde_json::json;
+/// use tauri_sys::tauri::invoke_json;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let response = invoke_json("greet", json!({ "name": "Tauri" })).await?;
+///
+/// log::info!("{}", response);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "json")]
+pub async fn invoke_json(cmd: &str, args: serde_json::Value) -> crate::Result<serde_json::Value> {
+    let raw = observed_invoke(cmd, json_to_js_value(&args)?).await?;
+
+    js_value_to_json(raw)
+}
+
+/// Converts a JSON value into the equivalent plain JS value.
+///
+/// Unlike `serde_wasm_bindgen::to_value`, objects are converted to plain JS objects instead of `Map`s.
+#[cfg(feature = "json")]
+pub fn json_to_js_value(value: &serde_json::Value) -> crate::Result<wasm_bindgen::JsValue> {
+    Ok(value.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+/// Converts a JS value into a JSON value.
+///
+/// Values that can't be represented as JSON, e.g. functions, result in an error.
+#[cfg(feature = "json")]
+pub fn js_value_to_json(value: wasm_bindgen::JsValue) -> crate::Result<serde_json::Value> {
+    crate::from_js(value, "tauri::js_value_to_json")
+}
+
+/// Sends a message to the backend, returning the pending response together with a handle that can abort it.
+///
+/// Aborting stops waiting for the response, the returned future then resolves to [`Error::Aborted`](crate::Error::Aborted).
+/// The backend is not notified by [`AbortHandle::abort`], commands that want to cooperate can
+/// listen for the `{cmd}:cancel` event sent by [`AbortHandle::abort_and_notify`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::tauri::invoke_abortable;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (response, handle) = invoke_abortable::<_, String>("index_files", &());
+///
+/// // e.g. when the user clicks a "cancel" button
+/// handle.abort();
+///
+/// assert!(response.await.is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn invoke_abortable<A: Serialize, R: DeserializeOwned>(
+    cmd: &str,
+    args: &A,
+) -> (impl Future<Output = crate::Result<R>>, AbortHandle) {
+    let (handle, registration) = future::AbortHandle::new_pair();
+    let args = serde_wasm_bindgen::to_value(args);
+    let owned_cmd = cmd.to_string();
+
+    let fut = async move {
+        let raw = future::Abortable::new(observed_invoke(&owned_cmd, args?), registration)
+            .await
+            .map_err(|_| crate::Error::Aborted(owned_cmd.clone()))??;
+
+        crate::from_js(raw, &owned_cmd)
+    };
+
+    (
+        fut,
+        AbortHandle {
+            #[cfg(feature = "event")]
+            cmd: cmd.to_string(),
+            handle,
+        },
+    )
+}
+
+/// A handle to abort a command invoked through [`invoke_abortable`].
+#[derive(Debug, Clone)]
+pub struct AbortHandle {
+    #[cfg(feature = "event")]
+    cmd: String,
+    handle: future::AbortHandle,
+}
+
+impl AbortHandle {
+    /// Stops waiting for the response of the command.
+    pub fn abort(&self) {
+        self.handle.abort();
+    }
+
+    /// Whether [`abort`](Self::abort) has been called.
+    pub fn is_aborted(&self) -> bool {
+        self.handle.is_aborted()
+    }
+
+    /// Stops waiting for the response of the command and emits the `{cmd}:cancel` event,
+    /// so the backend can stop the work it is doing.
+    ///
+    /// Requires the `event` feature.
+    #[cfg(feature = "event")]
+    pub async fn abort_and_notify(&self) -> crate::Result<()> {
+        self.abort();
+
+        crate::event::emit(&format!("{}:cancel", self.cmd), &()).await
+    }
+}
+
+/// Sends a message to the backend that streams its results back through events.
+///
+/// A unique event name is generated and injected into the arguments as the `channel` field,
+/// the backend emits each item to that event and resolves the command once it is done.
+/// The returned future must be polled for the command to be sent, the listener is set up before that so no items are lost.
+/// The stream ends once the command resolved and all items received up to that point have been yielded.
+///
+/// Requires the `event` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::tauri::streamed_invoke;
+///
+/// #[derive(serde::Serialize)]
+/// struct Args<'a> {
+///     path: &'a str,
+/// }
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let (done, lines) = streamed_invoke::<_, (), String>("read_lines", &Args { path: "foo.txt" }).await?;
+///
+/// let (res, lines) = futures::join!(done, lines.collect::<Vec<_>>());
+/// res?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "event")]
+pub async fn streamed_invoke<A, R, U>(
+    cmd: &str,
+    args: &A,
+) -> crate::Result<(
+    impl Future<Output = crate::Result<R>>,
+    impl futures::Stream<Item = U>,
+)>
+where
+    A: Serialize,
+    R: DeserializeOwned,
+    U: DeserializeOwned + 'static,
+{
+    use futures::{channel::oneshot, FutureExt, StreamExt};
+    use std::task::Poll;
+
+    let args = serde_wasm_bindgen::to_value(args)?;
+    let args = if args.is_undefined() || args.is_null() {
+        js_sys::Object::new().into()
+    } else if args.is_object() && !js_sys::Array::is_array(&args) {
+        args
+    } else {
+        return Err(crate::Error::Serde(format!(
+            "arguments of streamed command {:?} must serialize to a map",
+            cmd
+        )));
+    };
+
+    // plugin commands like `plugin:x|y` contain characters that are not allowed in event names
+    let channel = format!(
+        "tauri-sys:stream:{}",
+        (js_sys::Math::random() * u32::MAX as f64) as u32
+    );
+    js_sys::Reflect::set(&args, &"channel".into(), &channel.as_str().into())?;
+
+    let mut events = Box::pin(crate::event::listen::<U>(&channel).await?);
+    let (done_tx, mut done_rx) = oneshot::channel::<()>();
+    let cmd = cmd.to_string();
+
+    let response = async move {
+        let res = observed_invoke(&cmd, args).await;
+        let _ = done_tx.send(());
+
+        crate::from_js(res?, &cmd)
+    };
+
+    let mut done = false;
+    let items = futures::stream::poll_fn(move |cx| {
+        if let Poll::Ready(event) = events.poll_next_unpin(cx) {
+            return Poll::Ready(event.map(|event| event.payload));
+        }
+
+        if !done && done_rx.poll_unpin(cx).is_pending() {
+            return Poll::Pending;
+        }
+        done = true;
+
+        // the command resolved, drain what is buffered and end the stream
+        match events.poll_next_unpin(cx) {
+            Poll::Ready(event) => Poll::Ready(event.map(|event| event.payload)),
+            Poll::Pending => Poll::Ready(None),
+        }
+    });
+
+    Ok((response, items))
+}
+
+/// Timing information about a finished command invocation, passed to the [invoke observer](set_invoke_observer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvokeMetrics<'a> {
+    /// The command name.
+    pub cmd: &'a str,
+    /// The time between sending the command and receiving the response.
+    pub duration: Duration,
+    /// Whether the command resolved successfully.
+    pub ok: bool,
+}
+
+thread_local! {
+    static INVOKE_OBSERVER: Cell<Option<fn(&InvokeMetrics)>> = const { Cell::new(None) };
+}
+
+/// Sets a global hook that is called after every command invoked through this module finishes, or removes it when passed `None`.
+///
+/// Invocations that are dropped or [aborted](AbortHandle::abort) before the response arrives are not reported.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::tauri::{set_invoke_observer, InvokeMetrics};
+///
+/// fn observe(metrics: &InvokeMetrics) {
+///     log::debug!("{} took {:?} (ok: {})", metrics.cmd, metrics.duration, metrics.ok);
+/// }
+///
+/// set_invoke_observer(Some(observe));
+/// ```
+pub fn set_invoke_observer(observer: Option<fn(&InvokeMetrics)>) {
+    INVOKE_OBSERVER.with(|cell| cell.set(observer));
+}
+
+async fn observed_invoke(
+    cmd: &str,
+    args: wasm_bindgen::JsValue,
+) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue> {
+    let Some(observer) = INVOKE_OBSERVER.with(Cell::get) else {
+        return inner::invoke(cmd, args).await;
+    };
+
+    let start = js_sys::Date::now();
+    let res = inner::invoke(cmd, args).await;
+
+    observer(&InvokeMetrics {
+        cmd,
+        duration: Duration::from_secs_f64((js_sys::Date::now() - start).max(0.0) / 1000.0),
+        ok: res.is_ok(),
+    });
 
-    serde_wasm_bindgen::from_value(raw).map_err(Into::into)
+    res
 }
 
 /// Transforms a callback function to a string identifier that can be passed to the backend.
@@ -81,12 +490,16 @@ pub async fn transform_callback<T: DeserializeOwned>(
     once: bool,
 ) -> crate::Result<f64> {
     let js_val = inner::transformCallback(
-        &|raw| callback(serde_wasm_bindgen::from_value(raw).unwrap()),
+        &|raw| {
+            if let Some(value) = crate::closure_arg(raw, "callback") {
+                callback(value)
+            }
+        },
         once,
     )
     .await?;
 
-    Ok(serde_wasm_bindgen::from_value(js_val)?)
+    crate::from_js(js_val, "tauri::transform_callback")
 }
 
 mod inner {
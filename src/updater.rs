@@ -1,9 +1,9 @@
 //! Customize the auto updater flow.
 
-use futures::{Stream, channel::mpsc};
+use crate::event::Listen;
+use futures::{channel::mpsc, Stream};
 use serde::Deserialize;
 use wasm_bindgen::{prelude::Closure, JsValue};
-use crate::event::Listen;
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct UpdateManifest {
@@ -52,7 +52,7 @@ pub enum UpdateStatus {
 pub async fn check_update() -> crate::Result<UpdateResult> {
     let raw = inner::checkUpdate().await?;
 
-    Ok(serde_wasm_bindgen::from_value(raw)?)
+    crate::from_js(raw, "updater::check_update")
 }
 
 /// Install the update if there's one available.
@@ -78,11 +78,79 @@ pub async fn install_update() -> crate::Result<()> {
     Ok(())
 }
 
+/// Remembers that the user chose to skip `version`, e.g. from a "Skip this version" button.
+///
+/// Skipped versions are stored in `updater.json` in the app's config directory, see [`appdirs`](crate::appdirs).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::updater::{check_update, is_version_skipped, skip_version};
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let update = check_update().await?;
+///
+/// if let Some(manifest) = update.manifest {
+///     if !is_version_skipped(&manifest.version).await? {
+///         // ask the user, if they want to skip it:
+///         skip_version(&manifest.version).await?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Requires the `appdirs` feature.
+#[cfg(feature = "appdirs")]
+pub async fn skip_version(version: &str) -> crate::Result<()> {
+    let mut state: UpdaterState = crate::appdirs::read_config(UPDATER_STATE)
+        .await?
+        .unwrap_or_default();
+
+    if !state.is_skipped(version) {
+        state.skipped_versions.push(version.to_string());
+        crate::appdirs::write_config(UPDATER_STATE, &state).await?;
+    }
+
+    Ok(())
+}
+
+/// Whether `version` was skipped with [`skip_version`].
+///
+/// Requires the `appdirs` feature.
+#[cfg(feature = "appdirs")]
+pub async fn is_version_skipped(version: &str) -> crate::Result<bool> {
+    let state: UpdaterState = crate::appdirs::read_config(UPDATER_STATE)
+        .await?
+        .unwrap_or_default();
+
+    Ok(state.is_skipped(version))
+}
+
+#[cfg(feature = "appdirs")]
+const UPDATER_STATE: &str = "updater.json";
+
+#[cfg(feature = "appdirs")]
+#[derive(Default, Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct UpdaterState {
+    skipped_versions: Vec<String>,
+}
+
+#[cfg(feature = "appdirs")]
+impl UpdaterState {
+    fn is_skipped(&self, version: &str) -> bool {
+        self.skipped_versions
+            .iter()
+            .any(|skipped| skipped == version)
+    }
+}
+
 /// Listen to an updater event.
 ///
 /// The returned Future will automatically clean up it's underlying event listener when dropped, so no manual unlisten function needs to be called.
 /// See [Differences to the JavaScript API](../index.html#differences-to-the-javascript-api) for details.
-/// 
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -103,7 +171,10 @@ pub async fn updater_events() -> crate::Result<impl Stream<Item = Result<UpdateS
     let (tx, rx) = mpsc::unbounded::<Result<UpdateStatus, String>>();
 
     let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
-        let raw: UpdateStatusResult = serde_wasm_bindgen::from_value(raw).unwrap();
+        let Some(raw) = crate::closure_arg::<UpdateStatusResult>(raw, "tauri://update-status")
+        else {
+            return;
+        };
 
         let msg = if let Some(error) = raw.error {
             Err(error)
@@ -44,18 +44,115 @@
 //! It is recommended to allowlist only the APIs you use for optimal bundle size and security.
 
 use crate::{
-    event::{Event, Listen, Once},
+    event::{self, Event, EventSource},
     utils::ArrayIterator,
 };
-use futures::{
-    channel::{mpsc, oneshot},
-    Stream,
-};
+use futures::{channel::mpsc, future::Either, stream::LocalBoxStream, Stream, StreamExt};
 use js_sys::Array;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use std::fmt::Display;
+use std::{collections::VecDeque, fmt::Display, path::PathBuf, str::FromStr};
 use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
 
+/// A validated window label.
+///
+/// Labels may only contain alphanumeric characters `a-zA-Z0-9` plus the following special characters `-`, `/`, `:` and `_`.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::window::Label;
+///
+/// const _: () = assert!(Label::is_valid("main"));
+///
+/// let label = Label::new("settings/general").unwrap();
+/// assert_eq!(label.as_str(), "settings/general");
+/// assert!(Label::try_from("not valid!").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Label(String);
+
+impl Label {
+    /// Creates a new label, returning [`Error::InvalidLabel`](crate::Error::InvalidLabel) if it contains invalid characters.
+    pub fn new(label: impl Into<String>) -> crate::Result<Self> {
+        let label = label.into();
+
+        if Self::is_valid(&label) {
+            Ok(Self(label))
+        } else {
+            Err(crate::Error::InvalidLabel(label))
+        }
+    }
+
+    /// Checks whether the given string is a valid label.
+    ///
+    /// This is a `const fn`, so it can be used to validate labels at compile time.
+    pub const fn is_valid(label: &str) -> bool {
+        let bytes = label.as_bytes();
+
+        if bytes.is_empty() {
+            return false;
+        }
+
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'/' | b':' | b'_' => {}
+                _ => return false,
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Label {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for Label {
+    type Error = crate::Error;
+
+    fn try_from(label: &str) -> Result<Self, Self::Error> {
+        Self::new(label)
+    }
+}
+
+impl TryFrom<String> for Label {
+    type Error = crate::Error;
+
+    fn try_from(label: String) -> Result<Self, Self::Error> {
+        Self::new(label)
+    }
+}
+
+impl From<Label> for String {
+    fn from(label: Label) -> Self {
+        label.0
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Theme {
     #[serde(rename = "light")]
@@ -423,14 +520,209 @@ impl<'a> WebviewWindowBuilder<'a> {
 
     /// Creates a new webview window.
     ///
+    /// Fails with [`Error::InvalidLabel`](crate::Error::InvalidLabel) before contacting the backend if the label is not a valid [`Label`].
+    ///
     /// Requires [`allowlist > window > create`](https://tauri.app/v1/api/config#windowallowlistconfig.create) to be enabled.
     pub async fn build(&self) -> crate::Result<WebviewWindow> {
+        Label::new(self.label)?;
         let opts = serde_wasm_bindgen::to_value(&self.inner)?;
 
         let win = WebviewWindow(inner::WebviewWindow::new(self.label, opts));
-        win.once::<()>("tauri://created").await?;
+
+        {
+            let created = win.once::<()>("tauri://created");
+            let error = win.once::<String>("tauri://error");
+            futures::pin_mut!(created, error);
+
+            match futures::future::select(created, error).await {
+                Either::Left((created, _)) => {
+                    created?;
+                }
+                Either::Right((error, _)) => {
+                    return Err(CreateWindowError::parse(error?.payload).into());
+                }
+            }
+        }
+
         Ok(win)
     }
+
+    /// Creates a new webview window together with streams of its lifecycle events.
+    ///
+    /// The listeners are registered before the window is created, so no event emitted by the new window can be missed.
+    /// Creation errors are returned directly instead of through a `tauri://error` event.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use tauri_sys::window::WebviewWindowBuilder;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (window, mut events) = WebviewWindowBuilder::new("settings")
+    ///     .set_url("/settings")
+    ///     .build_with_events()
+    ///     .await?;
+    ///
+    /// events.destroyed.next().await;
+    /// log::info!("{} was closed", window.label());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires [`allowlist > window > create`](https://tauri.app/v1/api/config#windowallowlistconfig.create) to be enabled.
+    pub async fn build_with_events(&self) -> crate::Result<(WebviewWindow, WindowEvents)> {
+        Label::new(self.label)?;
+
+        #[derive(Serialize)]
+        struct SkipCreate {
+            skip: bool,
+        }
+        let handle = serde_wasm_bindgen::to_value(&SkipCreate { skip: true })?;
+        let win = WebviewWindow(inner::WebviewWindow::new(self.label, handle));
+
+        let events = WindowEvents {
+            close_requested: win.listen("tauri://close-requested").await?.boxed_local(),
+            destroyed: win.listen("tauri://destroyed").await?.boxed_local(),
+            resized: win.on_resized().await?.boxed_local(),
+            moved: win.on_moved().await?.boxed_local(),
+        };
+
+        // same as `{ label, ...options }` in `window.js`
+        let options = serde_wasm_bindgen::to_value(&self.inner)?;
+        js_sys::Reflect::set(
+            &options,
+            &JsValue::from_str("label"),
+            &JsValue::from_str(self.label),
+        )?;
+
+        let command = WindowCommand {
+            tauri_module: "Window",
+            message: WindowCommandMessage {
+                cmd: "createWebview",
+                data: CreateWebviewData { options },
+            },
+        };
+        inner::invoke("tauri", serde_wasm_bindgen::to_value(&command)?)
+            .await
+            .map_err(|err| {
+                CreateWindowError::parse(err.as_string().unwrap_or_else(|| format!("{:?}", err)))
+            })?;
+
+        Ok((win, events))
+    }
+}
+
+/// Why a window could not be created, as returned by [`WebviewWindowBuilder::build`].
+///
+/// The backend only reports an error message, the [`kind`](Self::kind) is derived from it on a best-effort basis.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::{window::{CreateWindowErrorKind, WebviewWindowBuilder}, Error};
+///
+/// # async fn main() {
+/// match WebviewWindowBuilder::new("settings").build().await {
+///     Ok(_window) => {}
+///     Err(Error::CreateWindow(err)) if err.kind() == CreateWindowErrorKind::LabelTaken => {
+///         log::info!("The settings window is already open");
+///     }
+///     Err(err) => log::error!("{}", err),
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateWindowError {
+    kind: CreateWindowErrorKind,
+    message: String,
+}
+
+/// The kind of a [`CreateWindowError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CreateWindowErrorKind {
+    /// A window with the same label already exists.
+    LabelTaken,
+    /// The window URL could not be parsed.
+    InvalidUrl,
+    /// Creating windows is not enabled in the allowlist.
+    NotAllowed,
+    /// The WebView2 runtime is not installed (Windows only).
+    Webview2Missing,
+    /// Any other error.
+    Other,
+}
+
+impl CreateWindowError {
+    /// Classifies an error message reported by the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tauri_sys::window::{CreateWindowError, CreateWindowErrorKind};
+    ///
+    /// let err = CreateWindowError::parse("a window with label `settings` already exists");
+    /// assert_eq!(err.kind(), CreateWindowErrorKind::LabelTaken);
+    /// assert_eq!(err.message(), "a window with label `settings` already exists");
+    ///
+    /// let err = CreateWindowError::parse("'window > create' not in the allowlist");
+    /// assert_eq!(err.kind(), CreateWindowErrorKind::NotAllowed);
+    ///
+    /// assert_eq!(CreateWindowError::parse("out of memory").kind(), CreateWindowErrorKind::Other);
+    /// ```
+    pub fn parse(message: impl Into<String>) -> Self {
+        let message = message.into();
+        let lower = message.to_lowercase();
+
+        let kind = if lower.contains("already exists") {
+            CreateWindowErrorKind::LabelTaken
+        } else if lower.contains("not in the allowlist") {
+            CreateWindowErrorKind::NotAllowed
+        } else if lower.contains("webview2") {
+            CreateWindowErrorKind::Webview2Missing
+        } else if lower.contains("url") {
+            CreateWindowErrorKind::InvalidUrl
+        } else {
+            CreateWindowErrorKind::Other
+        };
+
+        Self { kind, message }
+    }
+
+    pub fn kind(&self) -> CreateWindowErrorKind {
+        self.kind
+    }
+
+    /// The error message as reported by the backend.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for CreateWindowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CreateWindowError {}
+
+/// Lifecycle event streams of a window created with [`WebviewWindowBuilder::build_with_events`].
+///
+/// Each stream unlistens its event when dropped, fields that aren't needed can simply be dropped.
+pub struct WindowEvents {
+    /// The user requested the window to be closed.
+    ///
+    /// While this stream is alive the backend doesn't close the window on its own,
+    /// call [`WebviewWindow::close`] in response or drop the stream to restore the default behavior.
+    pub close_requested: LocalBoxStream<'static, Event<()>>,
+    /// The window has been destroyed.
+    pub destroyed: LocalBoxStream<'static, Event<()>>,
+    /// The window has been resized, see [`WebviewWindow::on_resized`].
+    pub resized: LocalBoxStream<'static, Event<PhysicalSize>>,
+    /// The window has been moved, see [`WebviewWindow::on_moved`].
+    pub moved: LocalBoxStream<'static, Event<PhysicalPosition>>,
 }
 
 /// Create new webview windows and get a handle to existing ones.
@@ -449,11 +741,17 @@ impl WebviewWindow {
         self.0.label()
     }
 
+    /// The label of this window as a validated [`Label`].
+    pub fn typed_label(&self) -> Label {
+        // the backend refuses to create windows with invalid labels, so this skips the check
+        Label(self.0.label())
+    }
+
     /// Returns the scale factor that can be used to map logical pixels to physical pixels, and vice versa.
     pub async fn scale_factor(&self) -> crate::Result<f64> {
         let js_val = self.0.scaleFactor().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::scale_factor")
     }
 
     /// Returns the position of the top-left hand corner of the window’s client area relative to the top-left hand corner of the desktop.
@@ -488,35 +786,170 @@ impl WebviewWindow {
     pub async fn is_fullscreen(&self) -> crate::Result<bool> {
         let js_val = self.0.isFullscreen().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::is_fullscreen")
     }
 
     /// Gets the window’s current maximized state.
     pub async fn is_maximized(&self) -> crate::Result<bool> {
         let js_val = self.0.isMaximized().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::is_maximized")
     }
 
     /// Gets the window’s current decoration state.
     pub async fn is_decorated(&self) -> crate::Result<bool> {
         let js_val = self.0.isDecorated().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::is_decorated")
     }
 
     /// Gets the window’s current resizable state.
     pub async fn is_resizable(&self) -> crate::Result<bool> {
         let js_val = self.0.isResizable().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::is_resizable")
     }
 
     /// Gets the window’s current visibility state.
     pub async fn is_visible(&self) -> crate::Result<bool> {
         let js_val = self.0.isVisible().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::is_visible")
+    }
+
+    /// Gets the window’s current focus state.
+    pub async fn is_focused(&self) -> crate::Result<bool> {
+        let js_val = self.0.isFocused().await?;
+
+        crate::from_js(js_val, "window::is_focused")
+    }
+
+    /// Fetches the commonly used attributes of this window at once.
+    ///
+    /// This takes one IPC round trip per attribute, not a single one: the v1 `manage` command carries exactly one
+    /// query and returns one value, and the backend has no batch query a frontend crate could rely on.
+    /// The requests are sent concurrently, so the snapshot takes about as long as the slowest of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::window::current_window;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let state = current_window().snapshot().await?;
+    ///
+    /// if state.is_focused {
+    ///     log::info!("{} is focused", state.label);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn snapshot(&self) -> crate::Result<WindowState> {
+        let (inner_size, outer_position, is_focused, is_visible, is_maximized, is_fullscreen) = futures::try_join!(
+            self.inner_size(),
+            self.outer_position(),
+            self.is_focused(),
+            self.is_visible(),
+            self.is_maximized(),
+            self.is_fullscreen(),
+        )?;
+
+        Ok(WindowState {
+            label: self.typed_label(),
+            inner_size,
+            outer_position,
+            is_focused,
+            is_visible,
+            is_maximized,
+            is_fullscreen,
+        })
+    }
+
+    /// Captures the position, size and maximized/fullscreen state of this window, e.g. to restore it on the next launch.
+    ///
+    /// The returned [`WindowGeometry`] implements `Serialize` and `Deserialize` so it can be persisted with [`fs`](crate::fs) or the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::window::current_window;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let geometry = current_window().capture_geometry().await?;
+    /// // persist `geometry` and later
+    /// current_window().apply_geometry(&geometry).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn capture_geometry(&self) -> crate::Result<WindowGeometry> {
+        let (inner_size, outer_position, is_maximized, is_fullscreen, mut monitors) = futures::try_join!(
+            self.inner_size(),
+            self.outer_position(),
+            self.is_maximized(),
+            self.is_fullscreen(),
+            available_monitors(),
+        )?;
+
+        let monitor = monitors
+            .find(|monitor| monitor.contains(&outer_position))
+            .and_then(|monitor| monitor.name());
+
+        Ok(WindowGeometry {
+            x: outer_position.x(),
+            y: outer_position.y(),
+            width: inner_size.width(),
+            height: inner_size.height(),
+            maximized: is_maximized,
+            fullscreen: is_fullscreen,
+            monitor,
+        })
+    }
+
+    /// Restores a [`WindowGeometry`] previously returned by [`WebviewWindow::capture_geometry`].
+    ///
+    /// If the geometry was captured on a monitor that is no longer connected, the window is centered instead of being moved off-screen.
+    ///
+    /// Requires [`allowlist > window > setSize`](https://tauri.app/v1/api/config#windowallowlistconfig.setsize), [`allowlist > window > setPosition`](https://tauri.app/v1/api/config#windowallowlistconfig.setposition),
+    /// [`allowlist > window > maximize`](https://tauri.app/v1/api/config#windowallowlistconfig.maximize), [`allowlist > window > unmaximize`](https://tauri.app/v1/api/config#windowallowlistconfig.unmaximize)
+    /// and [`allowlist > window > setFullscreen`](https://tauri.app/v1/api/config#windowallowlistconfig.setfullscreen) to be enabled.
+    pub async fn apply_geometry(&self, geometry: &WindowGeometry) -> crate::Result<()> {
+        let (is_maximized, is_fullscreen, mut monitors) = futures::try_join!(
+            self.is_maximized(),
+            self.is_fullscreen(),
+            available_monitors(),
+        )?;
+
+        // size and position are ignored by some platforms while the window is maximized or fullscreen
+        if is_fullscreen {
+            self.set_fullscreen(false).await?;
+        }
+        if is_maximized {
+            self.unmaximize().await?;
+        }
+
+        self.set_size(PhysicalSize::new(geometry.width, geometry.height))
+            .await?;
+
+        let position = PhysicalPosition::new(geometry.x, geometry.y);
+        let on_screen = monitors.any(|monitor| match &geometry.monitor {
+            Some(name) => monitor.name().as_ref() == Some(name),
+            None => monitor.contains(&position),
+        });
+
+        if on_screen {
+            self.set_position(position).await?;
+        } else {
+            self.center().await?;
+        }
+
+        if geometry.maximized {
+            self.maximize().await?;
+        }
+        if geometry.fullscreen {
+            self.set_fullscreen(true).await?;
+        }
+
+        Ok(())
     }
 
     /// Returns the current window theme.
@@ -526,7 +959,7 @@ impl WebviewWindow {
     pub async fn theme(&self) -> crate::Result<Theme> {
         let js_val = self.0.theme().await?;
 
-        Ok(serde_wasm_bindgen::from_value(js_val)?)
+        crate::from_js(js_val, "window::theme")
     }
 
     /// Centers the window.
@@ -536,6 +969,58 @@ impl WebviewWindow {
         Ok(self.0.center().await?)
     }
 
+    /// Centers this window on `monitor`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::window::{current_window, primary_monitor};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// if let Some(monitor) = primary_monitor().await? {
+    ///     current_window().center_on(&monitor).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Requires [`allowlist > window > setPosition`](https://tauri.app/v1/api/config#windowallowlistconfig.setposition) to be enabled.
+    pub async fn center_on(&self, monitor: &Monitor) -> crate::Result<()> {
+        let size = self.outer_size().await?;
+        let area = monitor.rect();
+
+        let x = i64::from(area.x) + (i64::from(area.width) - i64::from(size.width())) / 2;
+        let y = i64::from(area.y) + (i64::from(area.height) - i64::from(size.height())) / 2;
+
+        self.set_position(PhysicalPosition::new(x as i32, y as i32))
+            .await
+    }
+
+    /// Resizes this window to a fraction of the monitor it is on, e.g. `0.5` for half the monitor's width or height.
+    ///
+    /// Falls back to the primary monitor if the window's monitor can't be determined.
+    /// The v1 backend doesn't expose the monitor's work area, so the fractions refer to the full monitor size.
+    ///
+    /// Requires [`allowlist > window > setSize`](https://tauri.app/v1/api/config#windowallowlistconfig.setsize) to be enabled.
+    pub async fn set_size_relative(&self, width: f64, height: f64) -> crate::Result<()> {
+        let (position, mut monitors) =
+            futures::try_join!(self.outer_position(), available_monitors())?;
+
+        let monitor = match monitors.find(|monitor| monitor.contains(&position)) {
+            Some(monitor) => monitor,
+            None => primary_monitor().await?.ok_or_else(|| {
+                crate::Error::Command("no monitor found to size the window relative to".to_string())
+            })?,
+        };
+        let area = monitor.size();
+
+        self.set_size(PhysicalSize::new(
+            (area.width() as f64 * width).round() as u32,
+            (area.height() as f64 * height).round() as u32,
+        ))
+        .await
+    }
+
     /// Requests user attention to the window, this has no effect if the application is already focused. How requesting for user attention manifests is platform dependent, see UserAttentionType for details.
     ///
     /// Providing None will unset the request for user attention. Unsetting the request for user attention might not be done automatically by the WM when the window receives input.
@@ -557,8 +1042,8 @@ impl WebviewWindow {
     /// Currently only supported on macOS on wry. window.print() works on all platforms.
     ///
     /// Requires [`allowlist > window > print`](https://tauri.app/v1/api/config#windowallowlistconfig.print) to be enabled.
-    pub fn print(&self) -> crate::Result<()> {
-        todo!()
+    pub async fn print(&self) -> crate::Result<()> {
+        self.manage(ManageCommand::Print).await
     }
 
     /// Determines if this window should be resizable.
@@ -589,10 +1074,27 @@ impl WebviewWindow {
         Ok(self.0.unmaximize().await?)
     }
 
+    /// Maximizes this window if it isn't maximized, unmaximizes it otherwise.
+    ///
+    /// The state is flipped by the backend, so this doesn't race with other changes like querying the state first would.
+    ///
+    /// Requires [`allowlist > window > maximize`](https://tauri.app/v1/api/config#windowallowlistconfig.maximize) and [`allowlist > window > unmaximize`](https://tauri.app/v1/api/config#windowallowlistconfig.unmaximize) to be enabled.
     pub async fn toggle_maximize(&self) -> crate::Result<()> {
         Ok(self.0.toggleMaximize().await?)
     }
 
+    /// Makes this window fullscreen if it isn't, leaves fullscreen otherwise.
+    ///
+    /// The v1 backend has no toggle command, so the state is queried first. Returns whether the window is fullscreen now.
+    ///
+    /// Requires [`allowlist > window > setFullscreen`](https://tauri.app/v1/api/config#windowallowlistconfig.setfullscreen) to be enabled.
+    pub async fn toggle_fullscreen(&self) -> crate::Result<bool> {
+        let fullscreen = !self.is_fullscreen().await?;
+        self.set_fullscreen(fullscreen).await?;
+
+        Ok(fullscreen)
+    }
+
     /// Minimizes this window.
     ///
     /// Requires [`allowlist > window > minimize`](https://tauri.app/v1/api/config#windowallowlistconfig.minimize) to be enabled.
@@ -798,18 +1300,7 @@ impl WebviewWindow {
     where
         T: DeserializeOwned + 'static,
     {
-        let (tx, rx) = mpsc::unbounded::<Event<T>>();
-
-        let closure = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
-            let _ = tx.unbounded_send(serde_wasm_bindgen::from_value(raw).unwrap());
-        });
-        let unlisten = self.0.listen(event, &closure).await?;
-        closure.forget();
-
-        Ok(Listen {
-            rx,
-            unlisten: js_sys::Function::from(unlisten),
-        })
+        event::listen_on(&self.0, event).await
     }
 
     /// Listen to an one-off event emitted by the backend that is tied to the webview window.
@@ -821,90 +1312,901 @@ impl WebviewWindow {
     where
         T: DeserializeOwned + 'static,
     {
-        let (tx, rx) = oneshot::channel::<Event<T>>();
-
-        let closure: Closure<dyn FnMut(JsValue)> = Closure::once(move |raw| {
-            let _ = tx.send(serde_wasm_bindgen::from_value(raw).unwrap());
-        });
-        let unlisten = self.0.once(event, &closure).await?;
-        closure.forget();
-
-        let fut = Once {
-            rx,
-            unlisten: js_sys::Function::from(unlisten),
-        };
-
-        fut.await
+        event::once_on(&self.0, event).await
     }
-}
 
-/// A position represented in logical pixels.
-#[derive(Debug, Clone, PartialEq)]
-pub struct LogicalPosition(inner::LogicalPosition);
+    /// Sends a request to this window and waits for its reply.
+    ///
+    /// The receiving window handles requests with [`on_request`](Self::on_request) on the same `channel`.
+    /// Each request carries a unique id, the reply is sent back to the calling window only.
+    ///
+    /// Fails if no reply arrives within `timeout`, e.g. because the window doesn't handle `channel`, or if the window is closed before replying.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use tauri_sys::window::WebviewWindow;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let editor = WebviewWindow::get_by_label("editor").unwrap();
+    /// let is_dirty: bool = editor
+    ///     .request("is-dirty", &"notes.md", Duration::from_secs(5))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request<Req, Resp>(
+        &self,
+        channel: &str,
+        request: &Req,
+        timeout: std::time::Duration,
+    ) -> crate::Result<Resp>
+    where
+        Req: Serialize,
+        Resp: DeserializeOwned + 'static,
+    {
+        let caller = current_window();
+        let id = (js_sys::Math::random() * u32::MAX as f64) as u32;
 
-impl LogicalPosition {
-    pub fn new(x: i32, y: i32) -> Self {
-        Self(inner::LogicalPosition::new(x, y))
-    }
+        let mut replies =
+            event::listen_on::<_, Resp>(&caller.0, &format!("{}:reply:{}", channel, id)).await?;
+        let mut destroyed = self
+            .listen::<serde::de::IgnoredAny>("tauri://destroyed")
+            .await?;
 
-    pub fn from_physical(physical: impl Into<PhysicalPosition>, scale_factor: f64) -> Self {
-        physical.into().to_logical(scale_factor)
+        self.emit(
+            channel,
+            &RpcRequest {
+                id,
+                channel: channel.to_string(),
+                reply_to: caller.label(),
+                payload: request,
+            },
+        )
+        .await?;
+
+        let closed_or_timeout =
+            futures::future::select(destroyed.next(), Box::pin(crate::utils::sleep(timeout)));
+
+        match futures::future::select(replies.next(), closed_or_timeout).await {
+            Either::Left((Some(reply), _)) => Ok(reply.payload),
+            Either::Left((None, _)) => Err(crate::Error::Command(format!(
+                "no reply received on channel {:?}",
+                channel
+            ))),
+            Either::Right((Either::Left(_), _)) => Err(crate::Error::Command(format!(
+                "window {:?} was closed before replying on channel {:?}",
+                self.label(),
+                channel
+            ))),
+            Either::Right((Either::Right(_), _)) => Err(crate::Error::Command(format!(
+                "no reply received on channel {:?} within {:?}",
+                channel, timeout
+            ))),
+        }
     }
 
-    pub fn to_physical(self, scale_factor: f64) -> PhysicalPosition {
-        let x = self.x() as f64 * scale_factor;
-        let y = self.y() as f64 * scale_factor;
+    /// Listen to requests sent to this window with [`request`](Self::request) on `channel`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use tauri_sys::window::current_window;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut requests = current_window().on_request::<String>("is-dirty").await?;
+    ///
+    /// while let Some(request) = requests.next().await {
+    ///     let is_dirty = request.payload == "notes.md";
+    ///     request.reply(&is_dirty).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_request<Req>(
+        &self,
+        channel: &str,
+    ) -> crate::Result<impl Stream<Item = RpcRequest<Req>>>
+    where
+        Req: DeserializeOwned + 'static,
+    {
+        let events = self.listen::<RpcRequest<Req>>(channel).await?;
 
-        PhysicalPosition::new(x as i32, y as i32)
+        Ok(events.map(|event| event.payload))
     }
 
-    pub fn x(&self) -> i32 {
-        self.0.x()
-    }
-    pub fn set_x(&self, x: i32) {
-        self.0.set_x(x)
-    }
-    pub fn y(&self) -> i32 {
-        self.0.y()
-    }
-    pub fn set_y(&self, y: i32) {
-        self.0.set_y(y)
-    }
-}
+    /// Opens a typed, bidirectional channel between this window and the window labeled `other_label`.
+    ///
+    /// Both windows open the channel with the same `name` and each other's label.
+    /// [`WindowChannel::send`] waits until the peer opened the channel too, so no message is sent before
+    /// someone listens, and once the peer falls [`WINDOW_CHANNEL_CAPACITY`] messages behind,
+    /// so a slow receiver can't be flooded. Messages are delivered in order.
+    ///
+    /// The channel closes when the peer window is destroyed. If the peer's page is reloaded instead, messages
+    /// sent before it opens the channel again are lost, and the channel continues once it did.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use tauri_sys::window::current_window;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // in the "editor" window
+    /// let mut channel = current_window().open_channel::<String>("preview", "document").await?;
+    /// channel.send("# Hello".to_string()).await?;
+    ///
+    /// // in the "preview" window
+    /// let mut channel = current_window().open_channel::<String>("editor", "document").await?;
+    /// while let Some(markdown) = channel.next().await {
+    ///     log::info!("render {}", markdown);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_channel<T>(
+        &self,
+        other_label: &str,
+        name: &str,
+    ) -> crate::Result<WindowChannel<T>>
+    where
+        T: Serialize + DeserializeOwned + 'static,
+    {
+        let Some(peer) = WebviewWindow::get_by_label(other_label) else {
+            return Err(crate::Error::Command(format!(
+                "window {:?} does not exist",
+                other_label
+            )));
+        };
 
-impl From<LogicalPosition> for Position {
-    fn from(pos: LogicalPosition) -> Self {
-        Position::Logical(pos)
+        let frames = self
+            .listen::<ChannelFrame<T>>(&format!("{}:channel:{}", name, other_label))
+            .await?
+            .map(|event| event.payload);
+        let destroyed = peer
+            .listen::<serde::de::IgnoredAny>("tauri://destroyed")
+            .await?
+            .map(|_| ChannelFrame::Close);
+
+        let event = format!("{}:channel:{}", name, self.label());
+        // a peer that is already listening answers with `Ready`, otherwise it sends `Open` once it is
+        peer.emit(&event, &ChannelFrame::<()>::Open).await?;
+
+        Ok(WindowChannel {
+            peer,
+            event,
+            frames: futures::stream::select(frames, destroyed).boxed_local(),
+            pending: VecDeque::new(),
+            ready: false,
+            sent: 0,
+            acked: 0,
+            received: 0,
+            closed: false,
+        })
     }
-}
 
-/// A position represented in physical pixels.
-#[derive(Debug, Clone, PartialEq)]
-pub struct PhysicalPosition(inner::PhysicalPosition);
+    /// Sends a window manage command that has no wrapper in the bundled JS API.
+    async fn manage(&self, cmd: ManageCommand) -> crate::Result<()> {
+        let label = self.label();
+        let command = WindowCommand {
+            tauri_module: "Window",
+            message: WindowCommandMessage {
+                cmd: "manage",
+                data: WindowCommandData { label: &label, cmd },
+            },
+        };
 
-impl PhysicalPosition {
-    pub fn new(x: i32, y: i32) -> Self {
-        Self(inner::PhysicalPosition::new(x, y))
-    }
+        inner::invoke("tauri", serde_wasm_bindgen::to_value(&command)?).await?;
 
-    #[inline]
-    pub fn from_logical(logical: impl Into<LogicalPosition>, scale_factor: f64) -> Self {
-        logical.into().to_physical(scale_factor)
+        Ok(())
     }
 
-    #[inline]
-    pub fn to_logical(&self, scale_factor: f64) -> LogicalPosition {
-        let x = self.x() as f64 / scale_factor;
-        let y = self.y() as f64 / scale_factor;
+    /// Listen to the window being resized.
+    ///
+    /// The payload is the new inner size of the window, use `Event::<PhysicalSize>::to_logical` to convert it to logical pixels.
+    pub async fn on_resized(&self) -> crate::Result<impl Stream<Item = Event<PhysicalSize>>> {
+        let events = self.listen::<RawSize>("tauri://resize").await?;
 
-        LogicalPosition::new(x as i32, y as i32)
+        Ok(events.map(|event| Event {
+            event: event.event,
+            id: event.id,
+            payload: PhysicalSize::new(event.payload.width, event.payload.height),
+            window_label: event.window_label,
+        }))
     }
 
-    pub fn x(&self) -> i32 {
-        self.0.x()
+    /// Listen to the window being moved.
+    ///
+    /// The payload is the new outer position of the window, use `Event::<PhysicalPosition>::to_logical` to convert it to logical pixels.
+    pub async fn on_moved(&self) -> crate::Result<impl Stream<Item = Event<PhysicalPosition>>> {
+        let events = self.listen::<RawPosition>("tauri://move").await?;
+
+        Ok(events.map(|event| Event {
+            event: event.event,
+            id: event.id,
+            payload: PhysicalPosition::new(event.payload.x, event.payload.y),
+            window_label: event.window_label,
+        }))
     }
-    pub fn set_x(&self, x: i32) {
-        self.0.set_x(x)
+
+    /// Listen to clicks on the items of the window menu.
+    ///
+    /// Tauri v1 emits `tauri://menu` with the id of the clicked item to the window the menu is attached to.
+    /// The [`MenuEvent::window`] is taken from the label the event was delivered with,
+    /// so handlers shared between windows can tell which window triggered the click.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use tauri_sys::window::current_window;
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut clicks = current_window().on_menu_event().await?;
+    ///
+    /// while let Some(click) = clicks.next().await {
+    ///     log::info!("{} clicked in {:?}", click.id, click.window);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_menu_event(&self) -> crate::Result<impl Stream<Item = MenuEvent>> {
+        let events = self.listen::<String>("tauri://menu").await?;
+
+        Ok(events.map(|event| MenuEvent {
+            id: event.payload,
+            window: event.window_label.and_then(|label| Label::new(label).ok()),
+        }))
+    }
+
+    /// Listen to file drop events on the webview window.
+    ///
+    /// The three underlying `tauri://file-drop*` listeners are registered together and
+    /// their closures are owned by the returned stream, so they are unlistened and freed when the stream is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use tauri_sys::window::{current_window, FileDropEvent};
+    ///
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut events = current_window().on_file_drop_event().await?;
+    ///
+    /// while let Some(event) = events.next().await {
+    ///     if let FileDropEvent::Dropped(paths) = event.payload {
+    ///         log::info!("Dropped {:?}", paths);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn on_file_drop_event(
+        &self,
+    ) -> crate::Result<impl Stream<Item = Event<FileDropEvent>>> {
+        let (tx, rx) = mpsc::unbounded::<Event<FileDropEvent>>();
+        let mut listen = FileDropListen {
+            rx,
+            unlisten: Vec::with_capacity(3),
+            closures: Vec::with_capacity(3),
+        };
+
+        let hover_tx = tx.clone();
+        let hover = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+            let Some(event) =
+                crate::closure_arg::<Event<Vec<PathBuf>>>(raw, "tauri://file-drop-hover")
+            else {
+                return;
+            };
+            let _ = hover_tx.unbounded_send(Event {
+                event: event.event,
+                id: event.id,
+                payload: FileDropEvent::Hovered(event.payload),
+                window_label: event.window_label,
+            });
+        });
+        listen
+            .register(&self.0, "tauri://file-drop-hover", hover)
+            .await?;
+
+        let drop_tx = tx.clone();
+        let drop = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+            let Some(event) = crate::closure_arg::<Event<Vec<PathBuf>>>(raw, "tauri://file-drop")
+            else {
+                return;
+            };
+            let _ = drop_tx.unbounded_send(Event {
+                event: event.event,
+                id: event.id,
+                payload: FileDropEvent::Dropped(event.payload),
+                window_label: event.window_label,
+            });
+        });
+        listen.register(&self.0, "tauri://file-drop", drop).await?;
+
+        let cancel = Closure::<dyn FnMut(JsValue)>::new(move |raw| {
+            let Some(event) = crate::closure_arg::<Event<()>>(raw, "tauri://file-drop-cancelled")
+            else {
+                return;
+            };
+            let _ = tx.unbounded_send(Event {
+                event: event.event,
+                id: event.id,
+                payload: FileDropEvent::Cancelled,
+                window_label: event.window_label,
+            });
+        });
+        listen
+            .register(&self.0, "tauri://file-drop-cancelled", cancel)
+            .await?;
+
+        Ok(listen)
+    }
+
+    /// Forwards the `keydown` and `keyup` DOM events of this webview to the backend as `event`, with a [`KeyEvent`] payload.
+    ///
+    /// The DOM listeners are removed when the returned [`KeyForwarder`] is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_sys::window::current_window;
+    ///
+    /// let forwarder = current_window().forward_key_events("app://keys");
+    ///
+    /// // keep `forwarder` alive for as long as keys should be forwarded
+    /// ```
+    pub fn forward_key_events(&self, event: &str) -> KeyForwarder {
+        let handler = |state: KeyState| {
+            let window = self.clone();
+            let event = event.to_string();
+
+            Closure::<dyn FnMut(JsValue)>::new(move |raw: JsValue| {
+                let payload = KeyEvent::from_dom(state, &raw);
+                let window = window.clone();
+                let event = event.clone();
+
+                wasm_bindgen_futures::spawn_local(async move {
+                    if let Err(err) = window.emit(&event, &payload).await {
+                        log::error!("Failed to forward key event: {}", err);
+                    }
+                });
+            })
+        };
+
+        let forwarder = KeyForwarder {
+            keydown: handler(KeyState::Pressed),
+            keyup: handler(KeyState::Released),
+        };
+        forwarder.call_dom("addEventListener");
+
+        forwarder
+    }
+}
+
+/// A request received through [`WebviewWindow::on_request`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcRequest<T> {
+    id: u32,
+    channel: String,
+    reply_to: String,
+    /// The request sent by the other window.
+    pub payload: T,
+}
+
+impl<T> RpcRequest<T> {
+    /// The label of the window that sent the request.
+    pub fn sender(&self) -> &str {
+        &self.reply_to
+    }
+
+    /// Sends the reply back to the window that sent the request.
+    pub async fn reply<R: Serialize>(&self, reply: &R) -> crate::Result<()> {
+        let Some(sender) = WebviewWindow::get_by_label(&self.reply_to) else {
+            return Err(crate::Error::Command(format!(
+                "window {:?} that sent the request no longer exists",
+                self.reply_to
+            )));
+        };
+
+        sender
+            .emit(&format!("{}:reply:{}", self.channel, self.id), reply)
+            .await
+    }
+}
+
+/// The number of messages a [`WindowChannel`] may send before the peer has to acknowledge them.
+pub const WINDOW_CHANNEL_CAPACITY: u32 = 32;
+
+#[derive(Debug, Serialize, Deserialize)]
+enum ChannelFrame<T> {
+    Open,
+    Ready,
+    Data(T),
+    Ack(u32),
+    Close,
+}
+
+/// A typed channel between two windows, created with [`WebviewWindow::open_channel`].
+///
+/// Messages from the peer are read through the [`Stream`] implementation, which ends when the peer closes the channel
+/// or its window is destroyed. Dropping the channel closes it.
+pub struct WindowChannel<T> {
+    peer: WebviewWindow,
+    event: String,
+    frames: LocalBoxStream<'static, ChannelFrame<T>>,
+    pending: VecDeque<T>,
+    ready: bool,
+    sent: u32,
+    acked: u32,
+    received: u32,
+    closed: bool,
+}
+
+impl<T: Serialize + DeserializeOwned + 'static> WindowChannel<T> {
+    /// Sends a message to the peer window.
+    ///
+    /// Waits until the peer opened the channel and while [`WINDOW_CHANNEL_CAPACITY`] messages are unacknowledged,
+    /// messages received in the meantime are buffered.
+    pub async fn send(&mut self, message: T) -> crate::Result<()> {
+        while !self.closed
+            && (!self.ready || self.sent.wrapping_sub(self.acked) >= WINDOW_CHANNEL_CAPACITY)
+        {
+            match self.frames.next().await {
+                Some(frame) => {
+                    if let Some(message) = self.handle_frame(frame) {
+                        self.pending.push_back(message);
+                    }
+                }
+                None => self.closed = true,
+            }
+        }
+
+        if self.closed {
+            return Err(crate::Error::Command(format!(
+                "channel {:?} was closed by the peer",
+                self.event
+            )));
+        }
+
+        self.peer
+            .emit(&self.event, &ChannelFrame::Data(message))
+            .await?;
+        self.sent = self.sent.wrapping_add(1);
+
+        Ok(())
+    }
+
+    /// Closes the channel, ending the peer's stream.
+    pub async fn close(mut self) -> crate::Result<()> {
+        self.closed = true;
+
+        self.peer
+            .emit(&self.event, &ChannelFrame::<()>::Close)
+            .await
+    }
+
+    /// Returns the message carried by `frame`, if any.
+    fn handle_frame(&mut self, frame: ChannelFrame<T>) -> Option<T> {
+        match frame {
+            ChannelFrame::Open => {
+                // the peer (re)opened the channel, anything sent to an earlier instance is gone
+                self.ready = true;
+                self.sent = 0;
+                self.acked = 0;
+                self.received = 0;
+
+                let peer = self.peer.clone();
+                let event = self.event.clone();
+                wasm_bindgen_futures::spawn_local(async move {
+                    let _ = peer.emit(&event, &ChannelFrame::<()>::Ready).await;
+                });
+
+                None
+            }
+            ChannelFrame::Ready => {
+                self.ready = true;
+                None
+            }
+            ChannelFrame::Data(message) => {
+                self.received = self.received.wrapping_add(1);
+
+                if self.received.is_multiple_of(WINDOW_CHANNEL_CAPACITY / 2) {
+                    let peer = self.peer.clone();
+                    let event = self.event.clone();
+                    let received = self.received;
+
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let _ = peer.emit(&event, &ChannelFrame::<()>::Ack(received)).await;
+                    });
+                }
+
+                Some(message)
+            }
+            ChannelFrame::Ack(acked) => {
+                // acks can arrive out of order, only move forward in wrapping order
+                if (acked.wrapping_sub(self.acked) as i32) > 0 {
+                    self.acked = acked;
+                }
+                None
+            }
+            ChannelFrame::Close => {
+                self.closed = true;
+                None
+            }
+        }
+    }
+}
+
+// the buffered messages are never pinned
+impl<T> Unpin for WindowChannel<T> {}
+
+impl<T: Serialize + DeserializeOwned + 'static> Stream for WindowChannel<T> {
+    type Item = T;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        use std::task::Poll;
+
+        if let Some(message) = self.pending.pop_front() {
+            return Poll::Ready(Some(message));
+        }
+
+        while !self.closed {
+            match self.frames.poll_next_unpin(cx) {
+                Poll::Ready(Some(frame)) => {
+                    if let Some(message) = self.handle_frame(frame) {
+                        return Poll::Ready(Some(message));
+                    }
+                }
+                Poll::Ready(None) => self.closed = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+
+        Poll::Ready(None)
+    }
+}
+
+impl<T> Drop for WindowChannel<T> {
+    fn drop(&mut self) {
+        if self.closed {
+            return;
+        }
+
+        let peer = self.peer.clone();
+        let event = std::mem::take(&mut self.event);
+
+        wasm_bindgen_futures::spawn_local(async move {
+            let _ = peer.emit(&event, &ChannelFrame::<()>::Close).await;
+        });
+    }
+}
+
+/// A click on a menu item, yielded by [`WebviewWindow::on_menu_event`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MenuEvent {
+    /// The id of the clicked menu item.
+    pub id: String,
+    /// The window that triggered the click, if the backend provided its label.
+    pub window: Option<Label>,
+}
+
+/// Whether a key was pressed or released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum KeyState {
+    Pressed,
+    Released,
+}
+
+/// The modifier keys held during a [`KeyEvent`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Modifiers {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    /// The Command key on macOS, the Windows key elsewhere.
+    pub meta: bool,
+}
+
+/// The payload of key events forwarded by [`WebviewWindow::forward_key_events`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyEvent {
+    pub state: KeyState,
+    /// The [`key`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/key) value, e.g. `a` or `ArrowUp`.
+    pub key: String,
+    /// The physical [`code`](https://developer.mozilla.org/en-US/docs/Web/API/KeyboardEvent/code), e.g. `KeyA`.
+    pub code: String,
+    /// Whether the key is being held down and the event auto-repeats.
+    pub repeat: bool,
+    pub modifiers: Modifiers,
+    /// The key combination in accelerator syntax with the modifiers in a fixed order, e.g. `Ctrl+Shift+A`.
+    pub accelerator: String,
+}
+
+impl KeyEvent {
+    fn from_dom(state: KeyState, raw: &JsValue) -> Self {
+        let get = |field: &str| js_sys::Reflect::get(raw, &JsValue::from_str(field)).unwrap();
+        let flag = |field: &str| get(field).as_bool().unwrap_or(false);
+
+        let key = get("key").as_string().unwrap_or_default();
+        let modifiers = Modifiers {
+            ctrl: flag("ctrlKey"),
+            alt: flag("altKey"),
+            shift: flag("shiftKey"),
+            meta: flag("metaKey"),
+        };
+
+        let mut parts = Vec::new();
+        for (held, name) in [
+            (modifiers.ctrl, "Ctrl"),
+            (modifiers.alt, "Alt"),
+            (modifiers.shift, "Shift"),
+            (modifiers.meta, "Super"),
+        ] {
+            if held {
+                parts.push(name.to_string());
+            }
+        }
+        match key.as_str() {
+            "Control" | "Alt" | "Shift" | "Meta" => {}
+            " " => parts.push("Space".to_string()),
+            key if key.chars().count() == 1 => parts.push(key.to_uppercase()),
+            key => parts.push(key.to_string()),
+        }
+
+        Self {
+            state,
+            code: get("code").as_string().unwrap_or_default(),
+            repeat: flag("repeat"),
+            accelerator: parts.join("+"),
+            key,
+            modifiers,
+        }
+    }
+}
+
+/// Keeps the DOM listeners installed by [`WebviewWindow::forward_key_events`] alive, they are removed when this is dropped.
+pub struct KeyForwarder {
+    keydown: Closure<dyn FnMut(JsValue)>,
+    keyup: Closure<dyn FnMut(JsValue)>,
+}
+
+impl KeyForwarder {
+    fn call_dom(&self, method: &str) {
+        let global = js_sys::global();
+        let method: js_sys::Function = js_sys::Reflect::get(&global, &JsValue::from_str(method))
+            .unwrap()
+            .unchecked_into();
+
+        for (event, handler) in [("keydown", &self.keydown), ("keyup", &self.keyup)] {
+            method
+                .call2(&global, &JsValue::from_str(event), handler.as_ref())
+                .unwrap();
+        }
+    }
+}
+
+impl Drop for KeyForwarder {
+    fn drop(&mut self) {
+        log::debug!("Removing key event listeners");
+        self.call_dom("removeEventListener");
+    }
+}
+
+/// The payload of [`WebviewWindow::on_file_drop_event`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileDropEvent {
+    /// Files are being dragged over the webview.
+    Hovered(Vec<PathBuf>),
+    /// Files have been dropped onto the webview.
+    Dropped(Vec<PathBuf>),
+    /// The drag operation was cancelled.
+    Cancelled,
+}
+
+impl FileDropEvent {
+    /// The paths being dragged or dropped, empty if the operation was cancelled.
+    ///
+    /// The v1 backend doesn't report the cursor position of file drop events.
+    pub fn paths(&self) -> &[PathBuf] {
+        match self {
+            Self::Hovered(paths) | Self::Dropped(paths) => paths,
+            Self::Cancelled => &[],
+        }
+    }
+}
+
+struct FileDropListen {
+    rx: mpsc::UnboundedReceiver<Event<FileDropEvent>>,
+    unlisten: Vec<js_sys::Function>,
+    closures: Vec<Closure<dyn FnMut(JsValue)>>,
+}
+
+impl FileDropListen {
+    async fn register(
+        &mut self,
+        window: &inner::WebviewWindow,
+        event: &str,
+        closure: Closure<dyn FnMut(JsValue)>,
+    ) -> crate::Result<()> {
+        let unlisten = window.listen(event, &closure).await?;
+
+        self.unlisten.push(js_sys::Function::from(unlisten));
+        self.closures.push(closure);
+
+        Ok(())
+    }
+}
+
+impl Drop for FileDropListen {
+    fn drop(&mut self) {
+        log::debug!("Calling unlisten for file drop callbacks");
+        for unlisten in &self.unlisten {
+            unlisten.call0(&wasm_bindgen::JsValue::NULL).unwrap();
+        }
+    }
+}
+
+impl Stream for FileDropListen {
+    type Item = Event<FileDropEvent>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.rx.poll_next_unpin(cx)
+    }
+}
+
+#[derive(Serialize)]
+struct WindowCommand<'a, D> {
+    #[serde(rename = "__tauriModule")]
+    tauri_module: &'a str,
+    message: WindowCommandMessage<'a, D>,
+}
+
+#[derive(Serialize)]
+struct WindowCommandMessage<'a, D> {
+    cmd: &'a str,
+    data: D,
+}
+
+#[derive(Serialize)]
+struct WindowCommandData<'a> {
+    label: &'a str,
+    cmd: ManageCommand,
+}
+
+#[derive(Serialize)]
+struct CreateWebviewData {
+    #[serde(with = "serde_wasm_bindgen::preserve")]
+    options: JsValue,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", content = "payload", rename_all = "camelCase")]
+enum ManageCommand {
+    Print,
+}
+
+#[derive(Deserialize)]
+struct RawSize {
+    width: u32,
+    height: u32,
+}
+
+#[derive(Deserialize)]
+struct RawPosition {
+    x: i32,
+    y: i32,
+}
+
+impl Event<PhysicalPosition> {
+    /// Converts the payload to logical pixels using the current scale factor of `window`.
+    pub async fn to_logical(
+        &self,
+        window: &WebviewWindow,
+    ) -> crate::Result<Event<LogicalPosition>> {
+        let scale_factor = window.scale_factor().await?;
+
+        Ok(Event {
+            event: self.event.clone(),
+            id: self.id,
+            payload: self.payload.to_logical(scale_factor),
+            window_label: self.window_label.clone(),
+        })
+    }
+}
+
+impl Event<PhysicalSize> {
+    /// Converts the payload to logical pixels using the current scale factor of `window`.
+    pub async fn to_logical(&self, window: &WebviewWindow) -> crate::Result<Event<LogicalSize>> {
+        let scale_factor = window.scale_factor().await?;
+
+        Ok(Event {
+            event: self.event.clone(),
+            id: self.id,
+            payload: self.payload.to_logical(scale_factor),
+            window_label: self.window_label.clone(),
+        })
+    }
+}
+
+/// A position represented in logical pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalPosition(inner::LogicalPosition);
+
+impl LogicalPosition {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self(inner::LogicalPosition::new(x, y))
+    }
+
+    pub fn from_physical(physical: impl Into<PhysicalPosition>, scale_factor: f64) -> Self {
+        physical.into().to_logical(scale_factor)
+    }
+
+    pub fn to_physical(&self, scale_factor: f64) -> PhysicalPosition {
+        let x = self.x() as f64 * scale_factor;
+        let y = self.y() as f64 * scale_factor;
+
+        PhysicalPosition::new(x as i32, y as i32)
+    }
+
+    pub fn x(&self) -> i32 {
+        self.0.x()
+    }
+    pub fn set_x(&self, x: i32) {
+        self.0.set_x(x)
+    }
+    pub fn y(&self) -> i32 {
+        self.0.y()
+    }
+    pub fn set_y(&self, y: i32) {
+        self.0.set_y(y)
+    }
+}
+
+impl From<LogicalPosition> for Position {
+    fn from(pos: LogicalPosition) -> Self {
+        Position::Logical(pos)
+    }
+}
+
+/// A position represented in physical pixels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhysicalPosition(inner::PhysicalPosition);
+
+impl PhysicalPosition {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self(inner::PhysicalPosition::new(x, y))
+    }
+
+    #[inline]
+    pub fn from_logical(logical: impl Into<LogicalPosition>, scale_factor: f64) -> Self {
+        logical.into().to_physical(scale_factor)
+    }
+
+    #[inline]
+    pub fn to_logical(&self, scale_factor: f64) -> LogicalPosition {
+        let x = self.x() as f64 / scale_factor;
+        let y = self.y() as f64 / scale_factor;
+
+        LogicalPosition::new(x as i32, y as i32)
+    }
+
+    pub fn x(&self) -> i32 {
+        self.0.x()
+    }
+    pub fn set_x(&self, x: i32) {
+        self.0.set_x(x)
     }
     pub fn y(&self) -> i32 {
         self.0.y()
@@ -929,6 +2231,17 @@ impl LogicalSize {
         Self(inner::LogicalSize::new(x, y))
     }
 
+    pub fn from_physical(physical: impl Into<PhysicalSize>, scale_factor: f64) -> Self {
+        physical.into().to_logical(scale_factor)
+    }
+
+    pub fn to_physical(&self, scale_factor: f64) -> PhysicalSize {
+        let width = self.width() as f64 * scale_factor;
+        let height = self.height() as f64 * scale_factor;
+
+        PhysicalSize::new(width as u32, height as u32)
+    }
+
     pub fn width(&self) -> u32 {
         self.0.width()
     }
@@ -958,7 +2271,11 @@ impl PhysicalSize {
         Self(inner::PhysicalSize::new(x, y))
     }
 
-    pub fn to_logical(self, scale_factor: u32) -> LogicalSize {
+    pub fn from_logical(logical: impl Into<LogicalSize>, scale_factor: f64) -> Self {
+        logical.into().to_physical(scale_factor)
+    }
+
+    pub fn to_logical(&self, scale_factor: f64) -> LogicalSize {
         LogicalSize(self.0.toLogical(scale_factor))
     }
 
@@ -982,6 +2299,175 @@ impl From<PhysicalSize> for Size {
     }
 }
 
+/// A rectangle represented in logical pixels.
+///
+/// See [`PhysicalRect`] for an example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogicalRect {
+    /// The x coordinate of the top-left corner.
+    pub x: i32,
+    /// The y coordinate of the top-left corner.
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl LogicalRect {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a rectangle from its top-left corner and its size.
+    pub fn from_position_and_size(position: &LogicalPosition, size: &LogicalSize) -> Self {
+        Self::new(position.x(), position.y(), size.width(), size.height())
+    }
+
+    /// The top-left corner of the rectangle.
+    pub fn position(&self) -> LogicalPosition {
+        LogicalPosition::new(self.x, self.y)
+    }
+
+    pub fn size(&self) -> LogicalSize {
+        LogicalSize::new(self.width, self.height)
+    }
+
+    /// Whether the point (`x`, `y`) lies inside this rectangle.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        rect_contains(self.bounds(), (x, y))
+    }
+
+    /// The overlapping area of both rectangles, `None` if they don't overlap.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let (x, y, width, height) = rect_intersect(self.bounds(), other.bounds())?;
+
+        Some(Self::new(x, y, width, height))
+    }
+
+    pub fn to_physical(&self, scale_factor: f64) -> PhysicalRect {
+        PhysicalRect::new(
+            (self.x as f64 * scale_factor) as i32,
+            (self.y as f64 * scale_factor) as i32,
+            (self.width as f64 * scale_factor) as u32,
+            (self.height as f64 * scale_factor) as u32,
+        )
+    }
+
+    fn bounds(&self) -> (i32, i32, u32, u32) {
+        (self.x, self.y, self.width, self.height)
+    }
+}
+
+/// A rectangle represented in physical pixels.
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_sys::window::{LogicalRect, PhysicalRect};
+///
+/// let a = PhysicalRect::new(0, 0, 100, 100);
+/// let b = PhysicalRect::new(50, 50, 100, 100);
+///
+/// assert!(a.contains(99, 99));
+/// assert!(!a.contains(100, 0));
+/// assert_eq!(a.intersect(&b), Some(PhysicalRect::new(50, 50, 50, 50)));
+/// assert_eq!(a.intersect(&PhysicalRect::new(100, 0, 10, 10)), None);
+///
+/// assert_eq!(b.to_logical(2.0), LogicalRect::new(25, 25, 50, 50));
+/// assert_eq!(b.to_logical(2.0).to_physical(2.0), b);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhysicalRect {
+    /// The x coordinate of the top-left corner.
+    pub x: i32,
+    /// The y coordinate of the top-left corner.
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PhysicalRect {
+    pub fn new(x: i32, y: i32, width: u32, height: u32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a rectangle from its top-left corner and its size.
+    pub fn from_position_and_size(position: &PhysicalPosition, size: &PhysicalSize) -> Self {
+        Self::new(position.x(), position.y(), size.width(), size.height())
+    }
+
+    /// The top-left corner of the rectangle.
+    pub fn position(&self) -> PhysicalPosition {
+        PhysicalPosition::new(self.x, self.y)
+    }
+
+    pub fn size(&self) -> PhysicalSize {
+        PhysicalSize::new(self.width, self.height)
+    }
+
+    /// Whether the point (`x`, `y`) lies inside this rectangle.
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        rect_contains(self.bounds(), (x, y))
+    }
+
+    /// The overlapping area of both rectangles, `None` if they don't overlap.
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let (x, y, width, height) = rect_intersect(self.bounds(), other.bounds())?;
+
+        Some(Self::new(x, y, width, height))
+    }
+
+    pub fn to_logical(&self, scale_factor: f64) -> LogicalRect {
+        LogicalRect::new(
+            (self.x as f64 / scale_factor) as i32,
+            (self.y as f64 / scale_factor) as i32,
+            (self.width as f64 / scale_factor) as u32,
+            (self.height as f64 / scale_factor) as u32,
+        )
+    }
+
+    fn bounds(&self) -> (i32, i32, u32, u32) {
+        (self.x, self.y, self.width, self.height)
+    }
+}
+
+fn rect_contains((x, y, width, height): (i32, i32, u32, u32), (px, py): (i32, i32)) -> bool {
+    let dx = i64::from(px) - i64::from(x);
+    let dy = i64::from(py) - i64::from(y);
+
+    (0..i64::from(width)).contains(&dx) && (0..i64::from(height)).contains(&dy)
+}
+
+fn rect_intersect(
+    a: (i32, i32, u32, u32),
+    b: (i32, i32, u32, u32),
+) -> Option<(i32, i32, u32, u32)> {
+    let left = a.0.max(b.0);
+    let top = a.1.max(b.1);
+    let right = (i64::from(a.0) + i64::from(a.2)).min(i64::from(b.0) + i64::from(b.2));
+    let bottom = (i64::from(a.1) + i64::from(a.3)).min(i64::from(b.1) + i64::from(b.3));
+
+    if right <= i64::from(left) || bottom <= i64::from(top) {
+        return None;
+    }
+
+    Some((
+        left,
+        top,
+        (right - i64::from(left)) as u32,
+        (bottom - i64::from(top)) as u32,
+    ))
+}
+
 /// Allows you to retrieve information about a given monitor.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Monitor(JsValue);
@@ -1009,16 +2495,110 @@ impl Monitor {
     }
 
     /// The scale factor that can be used to map physical pixels to logical pixels.
-    pub fn scale_factor(&self) -> u32 {
-        let raw = js_sys::Reflect::get(&self.0, &JsValue::from_str("size"))
+    pub fn scale_factor(&self) -> f64 {
+        js_sys::Reflect::get(&self.0, &JsValue::from_str("scaleFactor"))
             .unwrap()
             .as_f64()
-            .unwrap();
+            .unwrap_or(1.0)
+    }
+
+    /// The monitor's resolution in logical pixels, using the monitor's own scale factor.
+    pub fn logical_size(&self) -> LogicalSize {
+        let size = self.size();
+        let scale_factor = self.scale_factor();
+
+        LogicalSize::new(
+            (size.width() as f64 / scale_factor) as u32,
+            (size.height() as f64 / scale_factor) as u32,
+        )
+    }
+
+    /// The area covered by this monitor in desktop coordinates.
+    pub fn rect(&self) -> PhysicalRect {
+        PhysicalRect::from_position_and_size(&self.position(), &self.size())
+    }
+
+    /// Whether the given point in desktop coordinates lies on this monitor.
+    pub fn contains(&self, point: &PhysicalPosition) -> bool {
+        self.rect().contains(point.x(), point.y())
+    }
+}
+
+impl Monitor {
+    fn key(&self) -> (Option<String>, u32, u32, i32, i32, u64) {
+        let size = self.size();
+        let position = self.position();
+
+        (
+            self.name(),
+            size.width(),
+            size.height(),
+            position.x(),
+            position.y(),
+            self.scale_factor().to_bits(),
+        )
+    }
+}
 
-        raw as u32
+impl Display for Monitor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let size = self.size();
+        let position = self.position();
+
+        write!(
+            f,
+            "{} ({}x{} at {},{}, scale {})",
+            self.name().as_deref().unwrap_or("<unnamed>"),
+            size.width(),
+            size.height(),
+            position.x(),
+            position.y(),
+            self.scale_factor()
+        )
     }
 }
 
+/// A snapshot of a window's attributes, as returned by [`WebviewWindow::snapshot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindowState {
+    /// The label of the window.
+    pub label: Label,
+    /// The physical size of the window’s client area.
+    pub inner_size: PhysicalSize,
+    /// The position of the top-left hand corner of the window relative to the top-left hand corner of the desktop.
+    pub outer_position: PhysicalPosition,
+    /// Whether the window is focused.
+    pub is_focused: bool,
+    /// Whether the window is visible.
+    pub is_visible: bool,
+    /// Whether the window is maximized.
+    pub is_maximized: bool,
+    /// Whether the window is fullscreen.
+    pub is_fullscreen: bool,
+}
+
+/// The geometry of a window, as returned by [`WebviewWindow::capture_geometry`].
+///
+/// Positions and sizes are in physical pixels.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowGeometry {
+    /// The x coordinate of the window's outer position.
+    pub x: i32,
+    /// The y coordinate of the window's outer position.
+    pub y: i32,
+    /// The width of the window’s client area.
+    pub width: u32,
+    /// The height of the window’s client area.
+    pub height: u32,
+    /// Whether the window is maximized.
+    pub maximized: bool,
+    /// Whether the window is fullscreen.
+    pub fullscreen: bool,
+    /// The name of the monitor the window is on, if it could be determined.
+    pub monitor: Option<String>,
+}
+
 /// Get an instance of [`WebviewWindow`] for the current webview window.
 ///
 /// # Example
@@ -1058,6 +2638,26 @@ pub fn all_windows() -> impl IntoIterator<Item = WebviewWindow> {
     ArrayIterator::new(raw).map(|r| WebviewWindow(inner::WebviewWindow::from(r)))
 }
 
+/// Gets a [`WindowState`] snapshot for all available webview windows.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_sys::window::all_windows_with_state;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// for state in all_windows_with_state().await? {
+///     log::info!("{} is {}x{}", state.label, state.inner_size.width(), state.inner_size.height());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn all_windows_with_state() -> crate::Result<Vec<WindowState>> {
+    let windows: Vec<_> = all_windows().into_iter().collect();
+
+    futures::future::try_join_all(windows.iter().map(WebviewWindow::snapshot)).await
+}
+
 /// Returns the monitor on which the window currently resides.
 ///
 /// Returns `None` if current monitor can't be detected.
@@ -1132,6 +2732,129 @@ pub async fn available_monitors() -> crate::Result<impl Iterator<Item = Monitor>
     Ok(monitors)
 }
 
+/// How often [`on_monitors_changed`] queries the monitors when the current window isn't moved.
+pub const MONITOR_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Listen to changes in the monitor configuration, e.g. displays being added, removed, moved or rescaled.
+///
+/// The backend doesn't emit a dedicated event for this, so the monitors are queried again whenever the current window
+/// is moved or its scale factor changes, and every [`MONITOR_POLL_INTERVAL`] to catch displays being plugged in or removed.
+/// The new list is yielded if it differs from the previous one.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::window::on_monitors_changed;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut changes = on_monitors_changed().await?;
+///
+/// while let Some(monitors) = changes.next().await {
+///     log::info!("{} monitors connected", monitors.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn on_monitors_changed() -> crate::Result<impl Stream<Item = Vec<Monitor>>> {
+    use serde::de::IgnoredAny;
+
+    let window = current_window();
+    let moved = window.listen::<IgnoredAny>("tauri://move").await?;
+    let rescaled = window.listen::<IgnoredAny>("tauri://scale-change").await?;
+
+    let mut last: Vec<_> = available_monitors().await?.map(|m| m.key()).collect();
+
+    let polled = futures::stream::unfold((), |_| async {
+        crate::utils::sleep(MONITOR_POLL_INTERVAL).await;
+        Some(((), ()))
+    });
+
+    let events = futures::stream::select(moved.map(|_| ()), rescaled.map(|_| ()));
+
+    let changes = futures::stream::select(events, polled)
+        .then(|_| async { available_monitors().await.map(Iterator::collect::<Vec<_>>) })
+        .filter_map(move |res| {
+            let changed = match res {
+                Ok(monitors) => {
+                    let keys: Vec<_> = monitors.iter().map(Monitor::key).collect();
+
+                    if keys != last {
+                        last = keys;
+                        Some(monitors)
+                    } else {
+                        None
+                    }
+                }
+                Err(err) => {
+                    log::error!("Failed to query monitors: {}", err);
+                    None
+                }
+            };
+
+            futures::future::ready(changed)
+        });
+
+    Ok(changes)
+}
+
+/// Listen to new windows being created, by this or any other window or the backend.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use futures::StreamExt;
+/// use tauri_sys::window::on_window_created;
+///
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut created = on_window_created().await?;
+///
+/// while let Some(created) = created.next().await {
+///     log::info!("Window {} was created", created.label);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn on_window_created() -> crate::Result<impl Stream<Item = CreatedWindow>> {
+    let events = event::listen::<CreatedWindow>("tauri://window-created").await?;
+
+    Ok(events.map(|event| event.payload))
+}
+
+/// The payload of [`on_window_created`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+pub struct CreatedWindow {
+    /// The label of the new window.
+    pub label: Label,
+}
+
+impl CreatedWindow {
+    /// Gets the [`WebviewWindow`] of the new window.
+    ///
+    /// Returns `None` if the window was closed in the meantime.
+    pub fn window(&self) -> Option<WebviewWindow> {
+        WebviewWindow::get_by_label(self.label.as_str())
+    }
+}
+
+impl EventSource for inner::WebviewWindow {
+    async fn listen_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue> {
+        self.listen(event, handler).await
+    }
+
+    async fn once_raw(
+        &self,
+        event: &str,
+        handler: &Closure<dyn FnMut(JsValue)>,
+    ) -> Result<JsValue, JsValue> {
+        self.once(event, handler).await
+    }
+}
+
 mod inner {
     use js_sys::Array;
     use wasm_bindgen::{
@@ -1196,7 +2919,7 @@ mod inner {
         #[wasm_bindgen(constructor)]
         pub fn new(width: u32, height: u32) -> PhysicalSize;
         #[wasm_bindgen(method)]
-        pub fn toLogical(this: &PhysicalSize, scaleFactor: u32) -> LogicalSize;
+        pub fn toLogical(this: &PhysicalSize, scaleFactor: f64) -> LogicalSize;
         #[wasm_bindgen(method, getter)]
         pub fn width(this: &PhysicalSize) -> u32;
         #[wasm_bindgen(method, setter)]
@@ -1263,6 +2986,8 @@ mod inner {
         #[wasm_bindgen(method, catch)]
         pub async fn isVisible(this: &WindowManager) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(method, catch)]
+        pub async fn isFocused(this: &WindowManager) -> Result<JsValue, JsValue>;
+        #[wasm_bindgen(method, catch)]
         pub async fn theme(this: &WindowManager) -> Result<JsValue, JsValue>;
         #[wasm_bindgen(method, catch)]
         pub async fn center(this: &WindowManager) -> Result<(), JsValue>;
@@ -1379,6 +3104,12 @@ mod inner {
         pub fn getByLabel(label: &str) -> Option<WebviewWindow>;
     }
 
+    #[wasm_bindgen(module = "/src/tauri.js")]
+    extern "C" {
+        #[wasm_bindgen(catch)]
+        pub async fn invoke(cmd: &str, args: JsValue) -> Result<JsValue, JsValue>;
+    }
+
     #[wasm_bindgen(module = "/src/window.js")]
     extern "C" {
         pub fn getCurrent() -> WebviewWindow;
@@ -107,3 +107,89 @@ async fn test_invoke() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+/**
+ * Window module
+ */
+
+#[wasm_bindgen_test]
+async fn test_build_with_events_sends_label_and_options() {
+    use tauri_sys::window::WebviewWindowBuilder;
+    use wasm_bindgen::JsValue;
+
+    mock_ipc(|cmd, payload| {
+        ensure!(cmd.as_str() == "tauri", "unknown command");
+
+        let request: ApiRequest = serde_wasm_bindgen::from_value(payload.clone()).unwrap();
+
+        match (
+            request.__tauri_module.as_str(),
+            request.message.cmd.as_str(),
+        ) {
+            ("Event", "listen") => Ok(JsValue::from(1)),
+            ("Window", "createWebview") => {
+                // IPC sends the payload through `JSON.stringify`, which drops the entries of a `Map`
+                let json = js_sys::JSON::stringify(&payload).unwrap();
+                let json: serde_json::Value = serde_json::from_str(&String::from(json)).unwrap();
+                let options = &json["message"]["data"]["options"];
+
+                ensure!(options["label"] == "settings");
+                ensure!(options["title"] == "Settings");
+
+                Ok(JsValue::NULL)
+            }
+            _ => Err(JsError::new("unexpected request")),
+        }
+    });
+
+    let (window, _events) = WebviewWindowBuilder::new("settings")
+        .set_title("Settings")
+        .build_with_events()
+        .await
+        .unwrap();
+
+    assert_eq!(window.label(), "settings");
+}
+
+/**
+ * Shell module
+ */
+
+#[wasm_bindgen_test]
+async fn test_spawn_events_end_after_terminated() {
+    use futures::StreamExt;
+    use tauri_sys::shell::{Command, CommandEvent};
+    use wasm_bindgen::{JsCast, JsValue};
+
+    mock_ipc(|cmd, payload| {
+        ensure!(cmd.as_str() == "tauri", "unknown command");
+
+        let message = js_sys::Reflect::get(&payload, &"message".into()).unwrap();
+        let on_event = js_sys::Reflect::get(&message, &"onEventFn".into()).unwrap();
+        let on_event: js_sys::Function = js_sys::Reflect::get(
+            &js_sys::global(),
+            &format!("_{}", on_event.as_f64().unwrap() as u64).into(),
+        )
+        .unwrap()
+        .unchecked_into();
+
+        for event in [
+            r#"{ "event": "Stdout", "payload": "hello" }"#,
+            r#"{ "event": "Terminated", "payload": { "code": 0, "signal": null } }"#,
+        ] {
+            on_event
+                .call1(&JsValue::NULL, &js_sys::JSON::parse(event).unwrap())
+                .unwrap();
+        }
+
+        Ok(42)
+    });
+
+    let (events, child) = Command::new("echo").spawn().await.unwrap();
+    let events: Vec<_> = events.collect().await;
+
+    assert_eq!(child.pid(), 42);
+    assert!(
+        matches!(events.as_slice(), [CommandEvent::Stdout(line), CommandEvent::Terminated(_)] if line == "hello")
+    );
+}